@@ -0,0 +1,427 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+/// The endpoints served from fixtures, along with their bundled rows.
+const BUNDLED: &[(&str, &str)] = &[
+    ("eod", include_str!("../fixtures/eod.json")),
+    ("intraday", include_str!("../fixtures/intraday.json")),
+    ("tickers", include_str!("../fixtures/tickers.json")),
+    ("exchanges", include_str!("../fixtures/exchanges.json")),
+    ("splits", include_str!("../fixtures/splits.json")),
+    ("dividends", include_str!("../fixtures/dividends.json")),
+    ("currencies", include_str!("../fixtures/currencies.json")),
+    ("timezones", include_str!("../fixtures/timezones.json")),
+];
+
+// Marketstack defaults to 100 results per page and allows up to 1000.
+const DEFAULT_LIMIT: usize = 100;
+const MAX_LIMIT: usize = 1000;
+
+/// A response to send, before it is written out.
+#[derive(Debug)]
+pub(crate) struct Reply {
+    pub(crate) status: u16,
+    pub(crate) headers: Vec<(&'static str, String)>,
+    pub(crate) body: Value,
+}
+
+impl Reply {
+    fn ok(body: Value) -> Self {
+        Self {
+            status: 200,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    /// An error in the format of Marketstack.
+    pub(crate) fn error(status: u16, code: &str, message: &str) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: json!({
+                "error": {
+                    "code": code,
+                    "message": message,
+                },
+            }),
+        }
+    }
+
+    /// A `validation_error` for a query parameter.
+    fn invalid(key: &str, message: &str) -> Self {
+        let mut reply = Self::error(
+            422,
+            "validation_error",
+            "Request failed with validation error",
+        );
+        reply.body["error"]["context"] = json!({
+            key: [{
+                "key": format!("invalid_{}", key),
+                "message": message,
+            }],
+        });
+        reply
+    }
+
+    fn not_found() -> Self {
+        Self::error(
+            404,
+            "404_not_found",
+            "The requested resource does not exist.",
+        )
+    }
+}
+
+/// The query parameters of a request.
+pub(crate) struct Query<'a> {
+    pairs: &'a [(String, String)],
+}
+
+impl<'a> Query<'a> {
+    pub(crate) fn new(pairs: &'a [(String, String)]) -> Self {
+        Self { pairs }
+    }
+
+    fn get(&self, key: &str) -> Option<&'a str> {
+        self.pairs
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// All values of a parameter, given either repeatedly or separated by commas.
+    fn all(&self, key: &str) -> BTreeSet<&'a str> {
+        self.pairs
+            .iter()
+            .filter(|(k, _)| k == key)
+            .flat_map(|(_, v)| v.split(','))
+            .filter(|v| !v.is_empty())
+            .collect()
+    }
+
+    fn number(&self, key: &str, default: usize) -> Result<usize, Reply> {
+        match self.get(key) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| Reply::invalid(key, &format!("The {} must be a whole number.", key))),
+            None => Ok(default),
+        }
+    }
+}
+
+/// The rows served for each endpoint.
+#[derive(Debug, Clone)]
+pub(crate) struct Fixtures {
+    rows: BTreeMap<&'static str, Vec<Value>>,
+    /// The most rows returned in one page, whatever the `limit`.
+    max_page_size: usize,
+}
+
+impl Fixtures {
+    /// The rows bundled with this crate.
+    pub(crate) fn bundled() -> Self {
+        let rows = BUNDLED
+            .iter()
+            .map(|&(endpoint, data)| (endpoint, serde_json::from_str(data).unwrap()))
+            .collect();
+
+        Self {
+            rows,
+            max_page_size: MAX_LIMIT,
+        }
+    }
+
+    /// Return at most the given number of rows per page.
+    pub(crate) fn set_max_page_size(&mut self, max_page_size: usize) {
+        self.max_page_size = max_page_size;
+    }
+
+    /// Replace the rows of an endpoint.
+    ///
+    /// Returns `false` if the endpoint is not served from fixtures.
+    pub(crate) fn insert(&mut self, endpoint: &str, rows: Vec<Value>) -> bool {
+        match BUNDLED.iter().find(|&&(name, _)| name == endpoint) {
+            Some(&(name, _)) => {
+                self.rows.insert(name, rows);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Replace the rows of every endpoint with a `<endpoint>.json` file in a directory.
+    pub(crate) fn load_dir(&mut self, dir: &Path) -> io::Result<()> {
+        for &(endpoint, _) in BUNDLED {
+            let path = dir.join(format!("{}.json", endpoint));
+            if !path.exists() {
+                continue;
+            }
+
+            let rows = serde_json::from_slice(&fs::read(&path)?).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: {}", path.display(), err),
+                )
+            })?;
+            self.rows.insert(endpoint, rows);
+        }
+
+        Ok(())
+    }
+
+    /// Respond to a request for a path below `/v1/`.
+    pub(crate) fn respond(&self, path: &str, query: &Query) -> Reply {
+        let segments: Vec<_> = path.split('/').collect();
+        let rows = match self.rows.get(segments[0]) {
+            Some(rows) => rows,
+            None => {
+                return Reply::error(
+                    403,
+                    "invalid_api_function",
+                    "The requested API endpoint does not exist.",
+                )
+            }
+        };
+
+        match (segments[0], &segments[1..]) {
+            (_, []) => self.list(filter(rows, query), query),
+            ("eod" | "intraday", ["latest"]) => {
+                let mut seen = BTreeSet::new();
+                let latest = filter(rows, query)
+                    .into_iter()
+                    .filter(|row| seen.insert(field(row, "symbol")))
+                    .collect();
+                self.list(latest, query)
+            }
+            ("eod" | "intraday", [date]) if is_date(date) => {
+                let on_date = filter(rows, query)
+                    .into_iter()
+                    .filter(|row| day(row) == Some(date))
+                    .collect();
+                self.list(on_date, query)
+            }
+            ("tickers", [symbol]) => find(rows, "symbol", symbol),
+            ("exchanges", [mic]) => find(rows, "mic", mic),
+            _ => Reply::not_found(),
+        }
+    }
+
+    /// A page of rows, as selected by `limit` and `offset`.
+    fn list(&self, rows: Vec<&Value>, query: &Query) -> Reply {
+        let page = query
+            .number("limit", DEFAULT_LIMIT)
+            .and_then(|limit| {
+                if (1..=MAX_LIMIT).contains(&limit) {
+                    Ok(limit)
+                } else {
+                    Err(Reply::invalid(
+                        "limit",
+                        &format!("The limit must be between 1 and {}.", MAX_LIMIT),
+                    ))
+                }
+            })
+            .and_then(|limit| Ok((limit, query.number("offset", 0)?)));
+        let (limit, offset) = match page {
+            Ok(page) => page,
+            Err(reply) => return reply,
+        };
+
+        let data: Vec<_> = rows
+            .iter()
+            .skip(offset)
+            .take(limit.min(self.max_page_size))
+            .collect();
+
+        Reply::ok(json!({
+            "pagination": {
+                "limit": limit,
+                "offset": offset,
+                "count": data.len(),
+                "total": rows.len(),
+            },
+            "data": data,
+        }))
+    }
+}
+
+fn field<'a>(row: &'a Value, key: &str) -> Option<&'a str> {
+    row.get(key).and_then(Value::as_str)
+}
+
+/// The `YYYY-MM-DD` part of the date of a row.
+fn day(row: &Value) -> Option<&str> {
+    field(row, "date").and_then(|date| date.get(..10))
+}
+
+fn is_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+}
+
+fn find(rows: &[Value], key: &str, value: &str) -> Reply {
+    rows.iter()
+        .find(|row| field(row, key) == Some(value))
+        .map(|row| Reply::ok(row.clone()))
+        .unwrap_or_else(Reply::not_found)
+}
+
+/// The rows matching the filters of a request, in the requested order.
+fn filter<'a>(rows: &'a [Value], query: &Query) -> Vec<&'a Value> {
+    let symbols = query.all("symbols");
+    let exchange = query.get("exchange");
+    let date_from = query.get("date_from");
+    let date_to = query.get("date_to");
+    let search = query.get("search").map(str::to_lowercase);
+
+    let mut rows: Vec<_> = rows
+        .iter()
+        .filter(|row| {
+            symbols.is_empty() || field(row, "symbol").is_none_or(|symbol| symbols.contains(symbol))
+        })
+        .filter(|row| {
+            exchange
+                .is_none_or(|exchange| field(row, "exchange").is_none_or(|value| value == exchange))
+        })
+        .filter(|row| match (day(row), date_from) {
+            (Some(day), Some(from)) => day >= from,
+            _ => true,
+        })
+        .filter(|row| match (day(row), date_to) {
+            (Some(day), Some(to)) => day <= to,
+            _ => true,
+        })
+        .filter(|row| {
+            search.as_ref().is_none_or(|search| {
+                ["name", "symbol"].iter().any(|key| {
+                    field(row, key).is_some_and(|value| value.to_lowercase().contains(search))
+                })
+            })
+        })
+        .collect();
+
+    // Fixtures are sorted newest first, which is the default order of Marketstack.
+    if query.get("sort") == Some("ASC") {
+        rows.reverse();
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::fixtures::{Fixtures, Query};
+
+    fn respond(path: &str, pairs: &[(&str, &str)]) -> (u16, Value) {
+        let pairs: Vec<_> = pairs
+            .iter()
+            .map(|&(k, v)| (k.to_owned(), v.to_owned()))
+            .collect();
+        let reply = Fixtures::bundled().respond(path, &Query::new(&pairs));
+        (reply.status, reply.body)
+    }
+
+    #[test]
+    fn test_pagination() {
+        let (status, body) = respond("eod", &[("limit", "30"), ("offset", "90")]);
+
+        assert_eq!(status, 200);
+        assert_eq!(
+            body["pagination"],
+            json!({"limit": 30, "offset": 90, "count": 10, "total": 100}),
+        );
+        assert_eq!(body["data"].as_array().unwrap().len(), 10);
+    }
+
+    #[test]
+    fn test_max_page_size() {
+        let mut fixtures = Fixtures::bundled();
+        fixtures.set_max_page_size(30);
+        let pairs = [("limit".to_owned(), "1000".to_owned())];
+        let reply = fixtures.respond("eod", &Query::new(&pairs));
+
+        assert_eq!(
+            reply.body["pagination"],
+            json!({"limit": 1000, "offset": 0, "count": 30, "total": 100}),
+        );
+    }
+
+    #[test]
+    fn test_invalid_limit() {
+        let (status, body) = respond("currencies", &[("limit", "1001")]);
+
+        assert_eq!(status, 422);
+        assert_eq!(body["error"]["code"], "validation_error");
+        assert!(body["error"]["context"]["limit"].is_array());
+    }
+
+    #[test]
+    fn test_filters() {
+        let (_, body) = respond(
+            "dividends",
+            &[
+                ("symbols", "AAPL,MSFT"),
+                ("date_from", "2023-01-01"),
+                ("date_to", "2023-12-31"),
+                ("sort", "ASC"),
+            ],
+        );
+        let dates: Vec<_> = body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|row| row["date"].as_str().unwrap())
+            .collect();
+
+        assert_eq!(dates.len(), 3);
+        assert!(dates.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let (_, body) = respond("dividends", &[("symbols", "GOOG")]);
+        assert_eq!(body["pagination"]["total"], 0);
+    }
+
+    #[test]
+    fn test_latest_and_date() {
+        let (_, body) = respond("eod/latest", &[("symbols", "AAPL")]);
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+        assert_eq!(body["data"][0]["date"], "2023-10-31T00:00:00+0000");
+
+        let (_, body) = respond("eod/2023-10-30", &[]);
+        assert_eq!(body["data"][0]["close"], 170.29);
+
+        let (status, _) = respond("eod/yesterday", &[]);
+        assert_eq!(status, 404);
+    }
+
+    #[test]
+    fn test_single_resources() {
+        let (status, body) = respond("tickers/AAPL", &[]);
+        assert_eq!(status, 200);
+        assert_eq!(body["name"], "Apple Inc");
+
+        let (_, body) = respond("exchanges/XNAS", &[]);
+        assert_eq!(body["acronym"], "NASDAQ");
+
+        let (status, body) = respond("tickers/NOPE", &[]);
+        assert_eq!(status, 404);
+        assert_eq!(body["error"]["code"], "404_not_found");
+    }
+
+    #[test]
+    fn test_unknown_endpoint() {
+        let (status, body) = respond("stockprice", &[]);
+
+        assert_eq!(status, 403);
+        assert_eq!(body["error"]["code"], "invalid_api_function");
+    }
+}
@@ -0,0 +1,41 @@
+//! A local stand-in for the Marketstack REST API.
+//!
+//! [`MockServer`] speaks the `v1` wire format of Marketstack on localhost, so that clients can be
+//! tested end to end without network access. It serves `eod`, `intraday`, `tickers`, `exchanges`,
+//! `splits`, `dividends`, `currencies` and `timezones` from fixture files, checks the
+//! `access_key` of every request and answers errors with Marketstack-style bodies.
+//!
+//! # Example
+//!
+//! ```rust
+//! use marketstack_mock::{Failure, MockServer};
+//!
+//! let server = MockServer::start().unwrap();
+//!
+//! // Point a client at the server, e.g.
+//! // `Marketstack::builder(server.host(), server.access_key()).insecure().build()`.
+//! println!("listening on http://{}/v1/", server.host());
+//!
+//! // Make the next request fail with `429 Too Many Requests`.
+//! server.fail_next(Failure::RateLimited);
+//! ```
+//!
+//! # Fixtures
+//!
+//! Each endpoint is backed by a JSON array of the rows Marketstack would return, sorted newest
+//! first. A small set of rows is bundled; [`MockServerBuilder::fixtures_dir`] replaces them with
+//! `<endpoint>.json` files from a directory.
+//!
+//! Lists support the pagination (`limit`, `offset`) and filters (`symbols`, `exchange`,
+//! `date_from`, `date_to`, `sort`, `search`) of Marketstack. `eod` and `intraday` support the
+//! `latest` and `[date]` features, and single tickers and exchanges are served at
+//! `tickers/[symbol]` and `exchanges/[mic]`. [`MockServerBuilder::max_page_size`] makes the small
+//! bundled fixtures span several pages.
+
+mod fixtures;
+mod server;
+
+pub use crate::server::Failure;
+pub use crate::server::MockServer;
+pub use crate::server::MockServerBuilder;
+pub use crate::server::DEFAULT_ACCESS_KEY;
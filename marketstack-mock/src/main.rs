@@ -0,0 +1,45 @@
+//! Serve the Marketstack stand-in until interrupted.
+//!
+//! Usage: `marketstack-mock [--port PORT] [--access-key KEY] [--fixtures DIR]`
+
+use std::env;
+use std::process;
+
+use marketstack_mock::MockServer;
+
+fn usage() -> ! {
+    eprintln!("usage: marketstack-mock [--port PORT] [--access-key KEY] [--fixtures DIR]");
+    process::exit(2);
+}
+
+fn main() {
+    let mut builder = MockServer::builder();
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let value = args.next().unwrap_or_else(|| usage());
+        match arg.as_str() {
+            "--port" => {
+                builder.port(value.parse().unwrap_or_else(|_| usage()));
+            }
+            "--access-key" => {
+                builder.access_key(value);
+            }
+            "--fixtures" => {
+                builder.fixtures_dir(value);
+            }
+            _ => usage(),
+        }
+    }
+
+    let server = builder.start().unwrap_or_else(|err| {
+        eprintln!("marketstack-mock: {}", err);
+        process::exit(1);
+    });
+    println!(
+        "serving http://{}/v1/ with access key `{}`",
+        server.host(),
+        server.access_key(),
+    );
+    server.wait();
+}
@@ -0,0 +1,343 @@
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use serde_json::Value;
+use url::Url;
+
+use crate::fixtures::{Fixtures, Query, Reply};
+
+/// The access key accepted by default.
+pub const DEFAULT_ACCESS_KEY: &str = "mock-access-key";
+
+/// A failure to inject into the responses of a [`MockServer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Failure {
+    /// `429 Too Many Requests` with a `rate_limit_reached` error and `Retry-After: 0`.
+    RateLimited,
+    /// `500 Internal Server Error` with an `internal_error` error.
+    InternalError,
+}
+
+impl Failure {
+    fn reply(self) -> Reply {
+        match self {
+            Failure::RateLimited => {
+                let mut reply = Reply::error(
+                    429,
+                    "rate_limit_reached",
+                    "You have exceeded the maximum rate limitation allowed on your subscription \
+                     plan.",
+                );
+                reply.headers.push(("retry-after", "0".into()));
+                reply
+            }
+            Failure::InternalError => {
+                Reply::error(500, "internal_error", "An internal error occurred.")
+            }
+        }
+    }
+}
+
+/// A builder for a [`MockServer`].
+#[derive(Debug, Clone)]
+pub struct MockServerBuilder {
+    access_key: String,
+    port: u16,
+    fixtures_dir: Option<PathBuf>,
+    fixtures: Vec<(String, Vec<Value>)>,
+    max_page_size: Option<usize>,
+}
+
+impl Default for MockServerBuilder {
+    fn default() -> Self {
+        Self {
+            access_key: DEFAULT_ACCESS_KEY.into(),
+            port: 0,
+            fixtures_dir: None,
+            fixtures: Vec::new(),
+            max_page_size: None,
+        }
+    }
+}
+
+impl MockServerBuilder {
+    /// The access key which requests must carry. Defaults to [`DEFAULT_ACCESS_KEY`].
+    pub fn access_key<K>(&mut self, access_key: K) -> &mut Self
+    where
+        K: Into<String>,
+    {
+        self.access_key = access_key.into();
+        self
+    }
+
+    /// The port to listen on. Defaults to any free port.
+    pub fn port(&mut self, port: u16) -> &mut Self {
+        self.port = port;
+        self
+    }
+
+    /// Serve the `<endpoint>.json` files of a directory instead of the bundled fixtures.
+    ///
+    /// Endpoints without a file keep their bundled rows.
+    pub fn fixtures_dir<P>(&mut self, dir: P) -> &mut Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.fixtures_dir = Some(dir.into());
+        self
+    }
+
+    /// Serve the given rows for an endpoint, e.g. `eod`.
+    pub fn fixture<E>(&mut self, endpoint: E, rows: Vec<Value>) -> &mut Self
+    where
+        E: Into<String>,
+    {
+        self.fixtures.push((endpoint.into(), rows));
+        self
+    }
+
+    /// Return at most this many rows per page, whatever the `limit` of a request.
+    ///
+    /// Lets small fixtures span several pages. Defaults to the 1000 rows Marketstack allows.
+    pub fn max_page_size(&mut self, max_page_size: usize) -> &mut Self {
+        self.max_page_size = Some(max_page_size);
+        self
+    }
+
+    /// Start serving on localhost.
+    pub fn start(&self) -> io::Result<MockServer> {
+        let mut fixtures = Fixtures::bundled();
+        if let Some(dir) = &self.fixtures_dir {
+            fixtures.load_dir(dir)?;
+        }
+        for (endpoint, rows) in &self.fixtures {
+            if !fixtures.insert(endpoint, rows.clone()) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("`{}` is not served from fixtures", endpoint),
+                ));
+            }
+        }
+        if let Some(max_page_size) = self.max_page_size {
+            fixtures.set_max_page_size(max_page_size);
+        }
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, self.port))?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(State {
+            access_key: self.access_key.clone(),
+            fixtures,
+            failures: Mutex::new(VecDeque::new()),
+            requests: Mutex::new(Vec::new()),
+            shutdown: AtomicBool::new(false),
+        });
+
+        let thread = {
+            let state = Arc::clone(&state);
+            thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if state.shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    let Ok(stream) = stream else { continue };
+                    let state = Arc::clone(&state);
+                    // Clients hanging up early are not the concern of the server.
+                    thread::spawn(move || {
+                        let _ = state.handle(stream);
+                    });
+                }
+            })
+        };
+
+        Ok(MockServer {
+            addr,
+            state,
+            thread: Some(thread),
+        })
+    }
+}
+
+/// A local stand-in for the Marketstack REST API.
+///
+/// The server stops when it is dropped.
+#[derive(Debug)]
+pub struct MockServer {
+    addr: SocketAddr,
+    state: Arc<State>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Create a builder for a server.
+    pub fn builder() -> MockServerBuilder {
+        MockServerBuilder::default()
+    }
+
+    /// Start a server with the bundled fixtures and [`DEFAULT_ACCESS_KEY`] on any free port.
+    pub fn start() -> io::Result<Self> {
+        Self::builder().start()
+    }
+
+    /// The `host:port` the server listens on, for use with an insecure client.
+    pub fn host(&self) -> String {
+        self.addr.to_string()
+    }
+
+    /// The base URL of the server, without the API version.
+    pub fn base_url(&self) -> Url {
+        Url::parse(&format!("http://{}/", self.addr)).unwrap()
+    }
+
+    /// The access key which requests must carry.
+    pub fn access_key(&self) -> &str {
+        &self.state.access_key
+    }
+
+    /// Fail the next request which does not already fail due to an earlier injection.
+    ///
+    /// Injected failures are answered in order, before the access key is checked.
+    pub fn fail_next(&self, failure: Failure) {
+        self.state.failures.lock().unwrap().push_back(failure);
+    }
+
+    /// The requests received so far, as paths with their query, without the access key.
+    pub fn requests(&self) -> Vec<String> {
+        self.state.requests.lock().unwrap().clone()
+    }
+
+    /// Serve until the process exits.
+    pub fn wait(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(thread) = self.thread.take() {
+            self.state.shutdown.store(true, Ordering::SeqCst);
+            // Wake up the listener so that it notices the shutdown.
+            let _ = TcpStream::connect(self.addr);
+            let _ = thread.join();
+        }
+    }
+}
+
+#[derive(Debug)]
+struct State {
+    access_key: String,
+    fixtures: Fixtures,
+    failures: Mutex<VecDeque<Failure>>,
+    requests: Mutex<Vec<String>>,
+    shutdown: AtomicBool,
+}
+
+impl State {
+    fn handle(&self, stream: TcpStream) -> io::Result<()> {
+        let mut reader = BufReader::new(stream);
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line)?;
+        // Requests to Marketstack carry no body, so the headers are all there is left.
+        let mut line = String::new();
+        while reader.read_line(&mut line)? > 2 {
+            line.clear();
+        }
+
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default();
+        let target = parts.next().unwrap_or_default();
+        let reply = self.respond(method, target);
+
+        let body = serde_json::to_vec(&reply.body)?;
+        let mut stream = reader.into_inner();
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n",
+            reply.status,
+            reason(reply.status),
+            body.len(),
+        )?;
+        for (key, value) in &reply.headers {
+            write!(stream, "{}: {}\r\n", key, value)?;
+        }
+        stream.write_all(b"\r\n")?;
+        stream.write_all(&body)?;
+        stream.flush()
+    }
+
+    fn respond(&self, method: &str, target: &str) -> Reply {
+        let url = match Url::parse("http://localhost/").and_then(|base| base.join(target)) {
+            Ok(url) => url,
+            Err(_) => return Reply::error(400, "bad_request", "The request is malformed."),
+        };
+        let pairs: Vec<(String, String)> = url.query_pairs().into_owned().collect();
+        self.record(&url, &pairs);
+
+        if let Some(failure) = self.failures.lock().unwrap().pop_front() {
+            return failure.reply();
+        }
+
+        match pairs.iter().find(|(key, _)| key == "access_key") {
+            None => {
+                return Reply::error(
+                    401,
+                    "missing_access_key",
+                    "You have not supplied an API Access Key. \
+                     [Required format: access_key=YOUR_ACCESS_KEY]",
+                )
+            }
+            Some((_, key)) if *key != self.access_key => {
+                return Reply::error(
+                    401,
+                    "invalid_access_key",
+                    "You have not supplied a valid API Access Key.",
+                )
+            }
+            Some(_) => (),
+        }
+
+        match url.path().strip_prefix("/v1/") {
+            Some(path) if method == "GET" => self.fixtures.respond(path, &Query::new(&pairs)),
+            _ => Reply::error(
+                403,
+                "invalid_api_function",
+                "The requested API endpoint does not exist.",
+            ),
+        }
+    }
+
+    fn record(&self, url: &Url, pairs: &[(String, String)]) {
+        let query = pairs
+            .iter()
+            .filter(|(key, _)| key != "access_key")
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<_>>()
+            .join("&");
+        let request = if query.is_empty() {
+            url.path().to_owned()
+        } else {
+            format!("{}?{}", url.path(), query)
+        };
+        self.requests.lock().unwrap().push(request);
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        422 => "Unprocessable Entity",
+        429 => "Too Many Requests",
+        _ => "Internal Server Error",
+    }
+}
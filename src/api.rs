@@ -14,7 +14,7 @@
 //! ```rust,no_run
 //! use serde::{Deserialize, Serialize};
 //! use marketstack::Marketstack;
-//! use marketstack::api::{self, Query};
+//! use marketstack::api::{self, Query, TypedEndpoint};
 //! use marketstack::api::eod;
 //! use marketstack::{PaginationInfo, EodDataItem};
 //!
@@ -37,6 +37,9 @@
 //! // Call the endpoint. The return type decides how to represent the value.
 //! let eod_data: EodData = endpoint.query(&client).unwrap();
 //!
+//! // OR let the endpoint decide, which deserializes into `marketstack::EodData`.
+//! let eod_data = endpoint.fetch(&client).unwrap();
+//!
 //! // Some endpoints support pagination. Since Marketstack does pagination through query
 //! // params, we simply specify them in the endpoint builder.
 //! // Note that there are limits defined, and therefore, limit(5) is fallible and returns
@@ -54,6 +57,7 @@ mod client;
 mod endpoint;
 mod error;
 mod ignore;
+mod meta;
 mod params;
 mod query;
 mod raw;
@@ -70,15 +74,19 @@ pub mod paged;
 pub mod splits;
 pub mod tickers;
 pub mod timezones;
+pub mod v2;
 
 pub use self::client::AsyncClient;
 pub use self::client::Client;
 pub use self::client::RestClient;
 
 pub use self::endpoint::Endpoint;
+pub use self::endpoint::TypedEndpoint;
 
 pub use self::error::ApiError;
 pub use self::error::BodyError;
+pub use self::error::ErrorCode;
+pub use self::error::ValidationError;
 
 pub use self::ignore::ignore;
 pub use self::ignore::Ignore;
@@ -87,10 +95,20 @@ pub use self::params::FormParams;
 pub use self::params::ParamValue;
 pub use self::params::QueryParams;
 
+pub(crate) use self::query::redact;
 pub use self::query::AsyncQuery;
 pub use self::query::Query;
+pub(crate) use self::query::REDACTED;
 
 pub use self::raw::raw;
 pub use self::raw::Raw;
 
+pub use self::meta::with_meta;
+pub use self::meta::Response;
+pub use self::meta::WithMeta;
+
+pub use self::paged::paged;
 pub use self::paged::PageLimit;
+pub use self::paged::Pageable;
+pub use self::paged::Paged;
+pub use self::paged::Pagination;
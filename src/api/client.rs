@@ -8,6 +8,7 @@ use url::Url;
 
 use crate::api::ApiError;
 use crate::auth::Auth;
+use crate::ApiVersion;
 
 /// A trait representing a client which can communicate with a Marketstack instance via REST.
 pub trait RestClient {
@@ -16,9 +17,19 @@ pub trait RestClient {
 
     /// Get the URL for the endpoint for the client.
     ///
-    /// This method adds the hostname for the client's target instance.
+    /// This method adds the hostname for the client's target instance and the version of the API
+    /// the client was built for.
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>>;
 
+    /// Get the URL for an endpoint of a specific version of the API.
+    ///
+    /// This method adds the hostname for the client's target instance and the given version.
+    fn versioned_rest_endpoint(
+        &self,
+        version: ApiVersion,
+        endpoint: &str,
+    ) -> Result<Url, ApiError<Self::Error>>;
+
     /// Get the Auth token from the client.
     fn get_auth(&self) -> Option<Auth>;
 }
@@ -5,6 +5,8 @@
 
 use std::borrow::Cow;
 
+use chrono::NaiveDate;
+
 use crate::api::ParamValue;
 
 /// Orderings for sorted results.
@@ -96,3 +98,50 @@ impl ParamValue<'static> for Interval {
         self.as_str().into()
     }
 }
+
+/// Data frequency for `commoditieshistory` endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    /// Daily prices.
+    Daily,
+    /// Weekly prices.
+    Weekly,
+    /// Monthly prices.
+    Monthly,
+    /// Quarterly prices.
+    Quarterly,
+    /// Yearly prices.
+    Yearly,
+}
+
+impl Frequency {
+    /// The string representation of the frequency.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Frequency::Daily => "1d",
+            Frequency::Weekly => "1w",
+            Frequency::Monthly => "1m",
+            Frequency::Quarterly => "1q",
+            Frequency::Yearly => "1y",
+        }
+    }
+}
+
+impl ParamValue<'static> for Frequency {
+    fn as_value(&self) -> Cow<'static, str> {
+        self.as_str().into()
+    }
+}
+
+/// Check that a range of dates given to a builder is not reversed.
+pub(crate) fn validate_date_range(
+    date_from: Option<NaiveDate>,
+    date_to: Option<NaiveDate>,
+) -> Result<(), String> {
+    match (date_from, date_to) {
+        (Some(from), Some(to)) if from > to => {
+            Err("`date_from` must not be after `date_to`".into())
+        }
+        _ => Ok(()),
+    }
+}
@@ -6,8 +6,9 @@ use chrono::NaiveDate;
 use derive_builder::Builder;
 
 use crate::api::common::SortOrder;
-use crate::api::paged::PaginationError;
+use crate::api::paged::{Pageable, PaginationError};
 use crate::api::{endpoint_prelude::*, ApiError};
+use crate::types::DividendsData;
 
 /// Query for `dividends`.
 #[derive(Debug, Builder, Clone)]
@@ -82,7 +83,7 @@ impl<'a> Endpoint for Dividends<'a> {
         "dividends".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params
@@ -97,6 +98,12 @@ impl<'a> Endpoint for Dividends<'a> {
     }
 }
 
+impl<'a> Pageable for Dividends<'a> {}
+
+impl<'a> TypedEndpoint for Dividends<'a> {
+    type Response = DividendsData;
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -105,7 +112,7 @@ mod tests {
     use crate::api::common::SortOrder;
     use crate::api::dividends::Dividends;
     use crate::api::{self, Query};
-    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
 
     #[test]
     fn dividends_defaults_are_sufficient() {
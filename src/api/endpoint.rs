@@ -1,10 +1,11 @@
 use async_trait::async_trait;
 use std::borrow::Cow;
 
-use http::{self, header, Method, Request};
+use http::{self, Method};
 use serde::de::DeserializeOwned;
 
 use crate::api::{query, ApiError, AsyncClient, AsyncQuery, BodyError, Client, Query, QueryParams};
+use crate::ApiVersion;
 
 /// A trait for providing the necessary information for a single REST API endpoint.
 pub trait Endpoint {
@@ -13,8 +14,15 @@ pub trait Endpoint {
     /// The path to the endpoint.
     fn endpoint(&self) -> Cow<'static, str>;
 
+    /// The version of the API the endpoint belongs to.
+    ///
+    /// Endpoints without one are sent to the version the client was built for.
+    fn api_version(&self) -> Option<ApiVersion> {
+        None
+    }
+
     /// Query parameters for the endpoint.
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         QueryParams::default()
     }
 
@@ -29,6 +37,55 @@ pub trait Endpoint {
     }
 }
 
+/// An endpoint which knows the type Marketstack responds with.
+///
+/// This allows calling [`TypedEndpoint::fetch`] without a type annotation. [`Query`] remains
+/// available to deserialize into custom types.
+#[async_trait]
+pub trait TypedEndpoint: Endpoint {
+    /// The type of the response data.
+    type Response: DeserializeOwned;
+
+    /// Deserialize the response data.
+    ///
+    /// Endpoints whose response shape depends on their parameters override this to pick the
+    /// right shape instead of guessing from the data.
+    ///
+    /// # Errors
+    /// This method returns an error if the data does not match the response type.
+    fn decode(&self, value: serde_json::Value) -> Result<Self::Response, serde_json::Error> {
+        serde_json::from_value(value)
+    }
+
+    /// Perform the query and deserialize into the response type of the endpoint.
+    fn fetch<C>(&self, client: &C) -> Result<Self::Response, ApiError<C::Error>>
+    where
+        C: Client,
+    {
+        let url = query::endpoint_url(self, client)?;
+        let rsp = query::send(self, url, client)?;
+        let v = query::response_json(&rsp)?;
+
+        self.decode(v)
+            .map_err(ApiError::data_type::<Self::Response>)
+    }
+
+    /// Perform the query asynchronously and deserialize into the response type of the endpoint.
+    async fn fetch_async<C>(&self, client: &C) -> Result<Self::Response, ApiError<C::Error>>
+    where
+        Self: Sync,
+        Self::Response: Send,
+        C: AsyncClient + Sync,
+    {
+        let url = query::endpoint_url(self, client)?;
+        let rsp = query::send_async(self, url, client).await?;
+        let v = query::response_json(&rsp)?;
+
+        self.decode(v)
+            .map_err(ApiError::data_type::<Self::Response>)
+    }
+}
+
 impl<E, T, C> Query<T, C> for E
 where
     E: Endpoint,
@@ -36,35 +93,9 @@ where
     C: Client,
 {
     fn query(&self, client: &C) -> Result<T, ApiError<<C>::Error>> {
-        let token = if let Some(token) = client.get_auth() {
-            token
-        } else {
-            return Err(ApiError::auth_error());
-        };
-        let mut url = client.rest_endpoint(&self.endpoint())?;
-        // Mutate every query with parameters that pushes access_key by default.
-        self.parameters()
-            .push("access_key", token)
-            .add_to_url(&mut url);
-        let req = Request::builder()
-            .method(self.method())
-            .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
-        let rsp = client.rest(req, data)?;
-        let status = rsp.status();
-        let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-            v
-        } else {
-            return Err(ApiError::server_error(status, rsp.body()));
-        };
-        if !status.is_success() {
-            return Err(ApiError::from_marketstack(v));
-        }
+        let url = query::endpoint_url(self, client)?;
+        let rsp = query::send(self, url, client)?;
+        let v = query::response_json(&rsp)?;
 
         serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
     }
@@ -78,36 +109,9 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<T, ApiError<C::Error>> {
-        let token = if let Some(token) = client.get_auth() {
-            token
-        } else {
-            return Err(ApiError::auth_error());
-        };
-        let mut url = client.rest_endpoint(&self.endpoint())?;
-        // Mutate every query with parameters that pushes access_key by default.
-        self.parameters()
-            .push("access_key", token)
-            .add_to_url(&mut url);
-
-        let req = Request::builder()
-            .method(self.method())
-            .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
-        let rsp = client.rest_async(req, data).await?;
-        let status = rsp.status();
-        let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-            v
-        } else {
-            return Err(ApiError::server_error(status, rsp.body()));
-        };
-        if !status.is_success() {
-            return Err(ApiError::from_marketstack(v));
-        }
+        let url = query::endpoint_url(self, client)?;
+        let rsp = query::send_async(self, url, client).await?;
+        let v = query::response_json(&rsp)?;
 
         serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)
     }
@@ -121,7 +125,7 @@ mod tests {
 
     use crate::api::endpoint_prelude::*;
     use crate::api::{ApiError, AsyncQuery, Query};
-    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
 
     struct Dummy;
 
@@ -140,6 +144,10 @@ mod tests {
         value: u8,
     }
 
+    impl TypedEndpoint for Dummy {
+        type Response = DummyResult;
+    }
+
     #[test]
     fn test_marketstack_non_json_response() {
         let endpoint = ExpectedUrl::builder()
@@ -300,4 +308,50 @@ mod tests {
         let res: DummyResult = Dummy.query_async(&client).await.unwrap();
         assert_eq!(res.value, 0);
     }
+
+    #[test]
+    fn test_fetch() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 0,
+            }),
+        );
+
+        let res = Dummy.fetch(&client).unwrap();
+        assert_eq!(res.value, 0);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_async() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 0,
+            }),
+        );
+
+        let res = Dummy.fetch_async(&client).await.unwrap();
+        assert_eq!(res.value, 0);
+    }
+
+    #[test]
+    fn test_fetch_bad_serialization() {
+        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "not_value": 0,
+            }),
+        );
+
+        let err = Dummy.fetch(&client).unwrap_err();
+        if let ApiError::DataType { typename, .. } = err {
+            assert_eq!(typename, "marketstack::api::endpoint::tests::DummyResult");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
 }
@@ -13,3 +13,5 @@ pub use crate::api::Client;
 pub use crate::api::Endpoint;
 pub use crate::api::FormParams;
 pub use crate::api::QueryParams;
+pub use crate::api::TypedEndpoint;
+pub use crate::ApiVersion;
@@ -71,8 +71,9 @@ use chrono::NaiveDate;
 use derive_builder::Builder;
 
 use crate::api::common::SortOrder;
-use crate::api::paged::PaginationError;
+use crate::api::paged::{Pageable, PaginationError};
 use crate::api::{endpoint_prelude::*, ApiError};
+use crate::types::EodData;
 
 /// Query for `eod`.
 #[derive(Debug, Builder, Clone)]
@@ -112,6 +113,10 @@ impl<'a> Eod<'a> {
     pub fn builder() -> EodBuilder<'a> {
         EodBuilder::default()
     }
+    /// Whether the endpoint is for a single day, i.e. `eod/latest` or `eod/[date]`.
+    pub(crate) fn is_single_day(&self) -> bool {
+        self.latest.is_some() || self.date.is_some()
+    }
 }
 
 impl<'a> EodBuilder<'a> {
@@ -172,7 +177,7 @@ impl<'a> Endpoint for Eod<'a> {
         }
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params
@@ -188,6 +193,12 @@ impl<'a> Endpoint for Eod<'a> {
     }
 }
 
+impl<'a> Pageable for Eod<'a> {}
+
+impl<'a> TypedEndpoint for Eod<'a> {
+    type Response = EodData;
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -196,7 +207,7 @@ mod tests {
     use crate::api::common::SortOrder;
     use crate::api::eod::Eod;
     use crate::api::{self, Query};
-    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
 
     #[test]
     fn eod_defaults_are_sufficient() {
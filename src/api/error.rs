@@ -1,6 +1,10 @@
 use std::any;
+use std::collections::BTreeMap;
 use std::error::Error;
+use std::fmt;
 
+use serde::Deserialize;
+use serde_json::Value;
 use thiserror::Error;
 
 use crate::api::paged::PaginationError;
@@ -19,6 +23,146 @@ pub enum BodyError {
     },
 }
 
+/// Error codes reported by Marketstack.
+///
+/// See the [Marketstack documentation](https://marketstack.com/documentation#api_errors) for
+/// the meaning of each code.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(from = "String")]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// The access key is not valid.
+    InvalidAccessKey,
+    /// No access key was supplied.
+    MissingAccessKey,
+    /// The account of the access key is inactive or blocked.
+    InactiveUser,
+    /// The subscription plan does not support HTTPS.
+    HttpsAccessRestricted,
+    /// The subscription plan does not support the requested endpoint.
+    FunctionAccessRestricted,
+    /// The requested endpoint does not exist.
+    InvalidApiFunction,
+    /// The requested resource does not exist.
+    NotFound,
+    /// The monthly request volume of the subscription plan has been reached.
+    UsageLimitReached,
+    /// Too many requests have been made in a short time.
+    RateLimitReached,
+    /// Too many symbols were requested at once.
+    TooManySymbols,
+    /// None of the requested symbols are valid.
+    NoValidSymbolsProvided,
+    /// A parameter failed validation; see the error context for details.
+    ValidationError,
+    /// Marketstack failed internally.
+    InternalError,
+    /// A code not known to this crate.
+    Other(String),
+}
+
+impl ErrorCode {
+    /// The code as reported by Marketstack.
+    pub fn as_str(&self) -> &str {
+        match self {
+            ErrorCode::InvalidAccessKey => "invalid_access_key",
+            ErrorCode::MissingAccessKey => "missing_access_key",
+            ErrorCode::InactiveUser => "inactive_user",
+            ErrorCode::HttpsAccessRestricted => "https_access_restricted",
+            ErrorCode::FunctionAccessRestricted => "function_access_restricted",
+            ErrorCode::InvalidApiFunction => "invalid_api_function",
+            ErrorCode::NotFound => "404_not_found",
+            ErrorCode::UsageLimitReached => "usage_limit_reached",
+            ErrorCode::RateLimitReached => "rate_limit_reached",
+            ErrorCode::TooManySymbols => "too_many_symbols",
+            ErrorCode::NoValidSymbolsProvided => "no_valid_symbols_provided",
+            ErrorCode::ValidationError => "validation_error",
+            ErrorCode::InternalError => "internal_error",
+            ErrorCode::Other(code) => code,
+        }
+    }
+
+    /// Whether the access key was not accepted.
+    pub fn is_invalid_key(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::InvalidAccessKey | ErrorCode::MissingAccessKey | ErrorCode::InactiveUser,
+        )
+    }
+
+    /// Whether the subscription plan does not cover the request.
+    pub fn is_plan_restricted(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::HttpsAccessRestricted | ErrorCode::FunctionAccessRestricted,
+        )
+    }
+
+    /// Whether the monthly request volume has been used up.
+    pub fn is_quota_exhausted(&self) -> bool {
+        matches!(self, ErrorCode::UsageLimitReached)
+    }
+
+    /// Whether the request was rejected for being made too soon.
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, ErrorCode::RateLimitReached)
+    }
+}
+
+impl From<&str> for ErrorCode {
+    fn from(code: &str) -> Self {
+        match code {
+            "invalid_access_key" => ErrorCode::InvalidAccessKey,
+            "missing_access_key" => ErrorCode::MissingAccessKey,
+            "inactive_user" => ErrorCode::InactiveUser,
+            "https_access_restricted" => ErrorCode::HttpsAccessRestricted,
+            "function_access_restricted" => ErrorCode::FunctionAccessRestricted,
+            "invalid_api_function" => ErrorCode::InvalidApiFunction,
+            "404_not_found" => ErrorCode::NotFound,
+            "usage_limit_reached" => ErrorCode::UsageLimitReached,
+            "rate_limit_reached" => ErrorCode::RateLimitReached,
+            "too_many_symbols" => ErrorCode::TooManySymbols,
+            "no_valid_symbols_provided" => ErrorCode::NoValidSymbolsProvided,
+            "validation_error" => ErrorCode::ValidationError,
+            "internal_error" => ErrorCode::InternalError,
+            code => ErrorCode::Other(code.into()),
+        }
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(code: String) -> Self {
+        code.as_str().into()
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A validation failure for a single request parameter.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ValidationError {
+    /// The kind of failure.
+    #[serde(default)]
+    pub key: String,
+    /// A description of the failure.
+    #[serde(default)]
+    pub message: String,
+}
+
+/// The `error` object of a Marketstack error response.
+#[derive(Deserialize)]
+struct ErrorEnvelope {
+    code: ErrorCode,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    context: Value,
+}
+
 /// Errors which may occur when using API endpoints.
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -71,6 +215,18 @@ where
         /// The error message from Marketstack.
         msg: String,
     },
+    /// Marketstack returned an error with a code.
+    #[error("marketstack error {}: {}", code, message)]
+    MarketstackError {
+        /// The error code.
+        code: ErrorCode,
+        /// The error message from Marketstack.
+        message: String,
+        /// The status code for the return.
+        status: http::StatusCode,
+        /// Validation failures, keyed by the parameter they concern.
+        context: BTreeMap<String, Vec<ValidationError>>,
+    },
     /// Marketstack returned an error without JSON information.
     #[error("marketstack internal server error: {}", status)]
     MarketstackService {
@@ -91,6 +247,12 @@ where
         /// The full object from Marketstack.
         obj: serde_json::Value,
     },
+    /// The monthly request budget of a rate limited client has been used up.
+    #[error("monthly request budget of {} exhausted", budget)]
+    QuotaExhausted {
+        /// The monthly budget which was configured.
+        budget: u64,
+    },
     /// Failed to parse an expected data type from JSON.
     #[error("could not parse {} data from JSON: {}", typename, source)]
     DataType {
@@ -123,6 +285,17 @@ where
             Self::Body { source } => ApiError::Body { source },
             Self::Json { source } => ApiError::Json { source },
             Self::Marketstack { msg } => ApiError::Marketstack { msg },
+            Self::MarketstackError {
+                code,
+                message,
+                status,
+                context,
+            } => ApiError::MarketstackError {
+                code,
+                message,
+                status,
+                context,
+            },
             Self::MarketstackService { status, data } => {
                 ApiError::MarketstackService { status, data }
             }
@@ -130,6 +303,16 @@ where
             Self::MarketstackUnrecognized { obj } => ApiError::MarketstackUnrecognized { obj },
             Self::DataType { source, typename } => ApiError::DataType { source, typename },
             Self::Pagination { source } => ApiError::Pagination { source },
+            Self::QuotaExhausted { budget } => ApiError::QuotaExhausted { budget },
+        }
+    }
+
+    /// The error code reported by Marketstack, if any.
+    pub fn error_code(&self) -> Option<&ErrorCode> {
+        if let Self::MarketstackError { code, .. } = self {
+            Some(code)
+        } else {
+            None
         }
     }
 
@@ -146,7 +329,21 @@ where
         }
     }
 
-    pub(crate) fn from_marketstack(value: serde_json::Value) -> Self {
+    pub(crate) fn from_marketstack(status: http::StatusCode, value: serde_json::Value) -> Self {
+        if let Some(envelope) = value
+            .get("error")
+            .and_then(|error| ErrorEnvelope::deserialize(error).ok())
+        {
+            // The context is only documented for validation errors; anything else is ignored.
+            let context = serde_json::from_value(envelope.context).unwrap_or_default();
+            return ApiError::MarketstackError {
+                code: envelope.code,
+                message: envelope.message,
+                status,
+                context,
+            };
+        }
+
         let error_value = value
             .pointer("/message")
             .or_else(|| value.pointer("/error"));
@@ -176,10 +373,11 @@ where
 mod tests {
     use core::panic;
 
+    use http::StatusCode;
     use serde_json::json;
     use thiserror::Error;
 
-    use crate::api::ApiError;
+    use crate::api::{ApiError, ErrorCode, ValidationError};
 
     #[derive(Debug, Error)]
     #[error("my error")]
@@ -191,7 +389,7 @@ mod tests {
             "error": "error contents"
         }};
 
-        let err: ApiError<MyError> = ApiError::from_marketstack(obj);
+        let err: ApiError<MyError> = ApiError::from_marketstack(StatusCode::BAD_REQUEST, obj);
         if let ApiError::Marketstack { msg } = err {
             assert_eq!(msg, "error contents");
         } else {
@@ -205,7 +403,7 @@ mod tests {
             "message": "error contents"
         });
 
-        let err: ApiError<MyError> = ApiError::from_marketstack(obj);
+        let err: ApiError<MyError> = ApiError::from_marketstack(StatusCode::BAD_REQUEST, obj);
         if let ApiError::Marketstack { msg } = err {
             assert_eq!(msg, "error contents");
         } else {
@@ -222,7 +420,7 @@ mod tests {
             "message": err_obj
         });
 
-        let err: ApiError<MyError> = ApiError::from_marketstack(obj);
+        let err: ApiError<MyError> = ApiError::from_marketstack(StatusCode::BAD_REQUEST, obj);
         if let ApiError::MarketstackObject { obj } = err {
             assert_eq!(obj, err_obj);
         } else {
@@ -236,11 +434,111 @@ mod tests {
             "some_weird_key": "an even weirder value",
         });
 
-        let err: ApiError<MyError> = ApiError::from_marketstack(err_obj.clone());
+        let err: ApiError<MyError> =
+            ApiError::from_marketstack(StatusCode::BAD_REQUEST, err_obj.clone());
         if let ApiError::MarketstackUnrecognized { obj } = err {
             assert_eq!(obj, err_obj);
         } else {
             panic!("unexpected error: {}", err);
         }
     }
+
+    #[test]
+    fn marketstack_error_code() {
+        let obj = json!({
+            "error": {
+                "code": "function_access_restricted",
+                "message": "Access Restricted - Your current Subscription Plan does not support this API Function.",
+            },
+        });
+
+        let err: ApiError<MyError> = ApiError::from_marketstack(StatusCode::FORBIDDEN, obj);
+        assert_eq!(err.error_code(), Some(&ErrorCode::FunctionAccessRestricted));
+        if let ApiError::MarketstackError {
+            code,
+            message,
+            status,
+            context,
+        } = err
+        {
+            assert!(code.is_plan_restricted());
+            assert!(message.starts_with("Access Restricted"));
+            assert_eq!(status, StatusCode::FORBIDDEN);
+            assert!(context.is_empty());
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn marketstack_error_validation_context() {
+        let obj = json!({
+            "error": {
+                "code": "validation_error",
+                "message": "Request failed with validation error",
+                "context": {
+                    "symbols": [
+                        {
+                            "key": "missing_symbols",
+                            "message": "You did not specify any symbols.",
+                        },
+                    ],
+                },
+            },
+        });
+
+        let err: ApiError<MyError> =
+            ApiError::from_marketstack(StatusCode::UNPROCESSABLE_ENTITY, obj);
+        if let ApiError::MarketstackError { code, context, .. } = err {
+            assert_eq!(code, ErrorCode::ValidationError);
+            assert_eq!(
+                context["symbols"],
+                vec![ValidationError {
+                    key: "missing_symbols".into(),
+                    message: "You did not specify any symbols.".into(),
+                }],
+            );
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn marketstack_error_unknown_code() {
+        let obj = json!({
+            "error": {
+                "code": "brand_new_code",
+                "message": "error contents",
+                "context": ["unexpected"],
+            },
+        });
+
+        let err: ApiError<MyError> = ApiError::from_marketstack(StatusCode::BAD_REQUEST, obj);
+        if let ApiError::MarketstackError { code, context, .. } = err {
+            assert_eq!(code, ErrorCode::Other("brand_new_code".into()));
+            assert_eq!(code.to_string(), "brand_new_code");
+            assert!(context.is_empty());
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn error_code_round_trip() {
+        for code in [
+            "invalid_access_key",
+            "usage_limit_reached",
+            "rate_limit_reached",
+            "too_many_symbols",
+            "no_valid_symbols_provided",
+            "404_not_found",
+        ] {
+            let parsed = ErrorCode::from(code);
+            assert!(!matches!(parsed, ErrorCode::Other(_)), "{}", code);
+            assert_eq!(parsed.as_str(), code);
+        }
+        assert!(ErrorCode::UsageLimitReached.is_quota_exhausted());
+        assert!(ErrorCode::RateLimitReached.is_rate_limited());
+        assert!(ErrorCode::InactiveUser.is_invalid_key());
+    }
 }
@@ -67,8 +67,10 @@ use std::borrow::Cow;
 use derive_builder::Builder;
 
 use crate::api::eod::Eod;
-use crate::api::paged::PaginationError;
+use crate::api::intraday::Intraday;
+use crate::api::paged::{Pageable, PaginationError};
 use crate::api::{endpoint_prelude::*, ApiError};
+use crate::types::ExchangesResponse;
 
 /// Base for `exchanges`.
 #[derive(Debug, Builder, Clone)]
@@ -86,6 +88,10 @@ pub struct Exchanges<'a> {
     /// Results in the `/exchanges/[mic]/eod` endpoint.
     #[builder(setter(into), default)]
     eod: Option<Eod<'a>>,
+    /// `Intraday` struct being built, and held by the `Exchanges` struct.
+    /// Results in the `/exchanges/[mic]/intraday` endpoint.
+    #[builder(setter(into), default)]
+    intraday: Option<Intraday<'a>>,
     /// Search stock exchanges by name or MIC.
     #[builder(setter(into), default)]
     search: Option<Cow<'a, str>>,
@@ -121,12 +127,15 @@ impl<'a> Endpoint for Exchanges<'a> {
             if let Some(eod) = &self.eod {
                 endpoint.push_str(&format!("/{}", eod.endpoint().as_ref()));
             }
+            if let Some(intraday) = &self.intraday {
+                endpoint.push_str(&format!("/{}", intraday.endpoint().as_ref()));
+            }
         }
 
         endpoint.into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         // NOTE: Not the most ergonomic way I want to go about this, but its okay for now since
@@ -135,6 +144,9 @@ impl<'a> Endpoint for Exchanges<'a> {
         if let Some(eod) = &self.eod {
             params = eod.parameters().clone();
         }
+        if let Some(intraday) = &self.intraday {
+            params = intraday.parameters().clone();
+        }
 
         params
             .push_opt("search", self.search.as_ref())
@@ -145,6 +157,35 @@ impl<'a> Endpoint for Exchanges<'a> {
     }
 }
 
+impl<'a> Pageable for Exchanges<'a> {
+    // Only the listing is a paginated array; the exchange itself is a single object and
+    // `tickers`, `eod` and `intraday` wrap their data in an object.
+    fn is_pageable(&self) -> bool {
+        self.mic.is_none()
+    }
+}
+
+impl<'a> TypedEndpoint for Exchanges<'a> {
+    type Response = ExchangesResponse;
+
+    fn decode(&self, value: serde_json::Value) -> Result<ExchangesResponse, serde_json::Error> {
+        // Nested endpoints are only requested along with a MIC.
+        if self.mic.is_none() {
+            return serde_json::from_value(value).map(ExchangesResponse::List);
+        }
+
+        if self.eod.is_some() {
+            serde_json::from_value(value).map(ExchangesResponse::Eod)
+        } else if self.intraday.is_some() {
+            serde_json::from_value(value).map(ExchangesResponse::Intraday)
+        } else if self.tickers.is_some() {
+            serde_json::from_value(value).map(ExchangesResponse::Tickers)
+        } else {
+            serde_json::from_value(value).map(ExchangesResponse::Exchange)
+        }
+    }
+}
+
 impl<'a> ExchangesBuilder<'a> {
     /// Limit the number of results returned.
     pub fn limit(&mut self, limit: u16) -> Result<&mut Self, ApiError<PaginationError>> {
@@ -155,7 +196,11 @@ impl<'a> ExchangesBuilder<'a> {
 
     /// Check that `Exchanges` contains valid endpoint combinations
     fn validate(&self) -> Result<(), String> {
-        let active_fields = [self.tickers.is_some(), self.eod.is_some()];
+        let active_fields = [
+            self.tickers.is_some(),
+            self.eod.is_some(),
+            self.intraday.is_some(),
+        ];
         let count = active_fields.iter().filter(|x| **x).count();
 
         if count > 1 {
@@ -173,8 +218,11 @@ mod tests {
 
     use crate::api::eod::Eod;
     use crate::api::exchanges::Exchanges;
-    use crate::api::{self, Query};
-    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::api::intraday::Intraday;
+    use crate::api::paged::{Pagination, PaginationError};
+    use crate::api::{self, ApiError, Query};
+    use crate::testing::{ExpectedUrl, MockClient, SingleTestClient};
+    use crate::types::EodDataItem;
 
     #[test]
     fn exchanges_defaults_are_sufficient() {
@@ -280,4 +328,54 @@ mod tests {
             .unwrap();
         api::ignore(endpoint).query(&client).unwrap();
     }
+
+    #[test]
+    fn exchanges_mic_intraday() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("exchanges/XNAS/intraday")
+            .add_query_params(&[("symbols", "AAPL")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Exchanges::builder()
+            .mic("XNAS")
+            .intraday(Intraday::builder().symbol("AAPL").build().unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn exchanges_validator() {
+        let endpoint = Exchanges::builder()
+            .mic("XNAS")
+            .eod(Eod::builder().build().unwrap())
+            .intraday(Intraday::builder().build().unwrap())
+            .build();
+        assert!(endpoint.is_err());
+        assert!(endpoint.err().unwrap().to_string().contains("Invalid"));
+    }
+
+    #[test]
+    fn exchanges_nested_is_not_pageable() {
+        // No request may be sent.
+        let client = MockClient::new();
+
+        let endpoint = Exchanges::builder()
+            .mic("XNAS")
+            .eod(Eod::builder().build().unwrap())
+            .build()
+            .unwrap();
+        let res: Result<Vec<EodDataItem>, _> = api::paged(endpoint, Pagination::All).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::Pagination {
+            source: PaginationError::NotPageable { endpoint },
+        } = err
+        {
+            assert_eq!(endpoint, "exchanges/XNAS/eod");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
 }
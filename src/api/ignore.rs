@@ -1,5 +1,4 @@
 use async_trait::async_trait;
-use http::{header, Request};
 
 use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query};
 
@@ -20,27 +19,9 @@ where
     C: Client,
 {
     fn query(&self, client: &C) -> Result<(), ApiError<<C>::Error>> {
-        let mut url = client.rest_endpoint(&self.endpoint.endpoint())?;
-        self.endpoint.parameters().add_to_url(&mut url);
-
-        let req = Request::builder()
-            .method(self.endpoint.method())
-            .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.endpoint.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
-        let rsp = client.rest(req, data)?;
-        if !rsp.status().is_success() {
-            let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-                v
-            } else {
-                return Err(ApiError::server_error(rsp.status(), rsp.body()));
-            };
-            return Err(ApiError::from_marketstack(v));
-        }
+        let url = query::endpoint_url(&self.endpoint, client)?;
+        let rsp = query::send(&self.endpoint, url, client)?;
+        query::check_status(&rsp)?;
 
         Ok(())
     }
@@ -53,27 +34,9 @@ where
     C: AsyncClient + Sync,
 {
     async fn query_async(&self, client: &C) -> Result<(), ApiError<C::Error>> {
-        let mut url = client.rest_endpoint(&self.endpoint.endpoint())?;
-        self.endpoint.parameters().add_to_url(&mut url);
-
-        let req = Request::builder()
-            .method(self.endpoint.method())
-            .uri(query::url_to_http_uri(url));
-        let (req, data) = if let Some((mime, data)) = self.endpoint.body()? {
-            let req = req.header(header::CONTENT_TYPE, mime);
-            (req, data)
-        } else {
-            (req, Vec::new())
-        };
-        let rsp = client.rest_async(req, data).await?;
-        if !rsp.status().is_success() {
-            let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
-                v
-            } else {
-                return Err(ApiError::server_error(rsp.status(), rsp.body()));
-            };
-            return Err(ApiError::from_marketstack(v));
-        }
+        let url = query::endpoint_url(&self.endpoint, client)?;
+        let rsp = query::send_async(&self.endpoint, url, client).await?;
+        query::check_status(&rsp)?;
 
         Ok(())
     }
@@ -86,7 +49,7 @@ mod tests {
 
     use crate::api::endpoint_prelude::*;
     use crate::api::{self, ApiError, AsyncQuery, Query};
-    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
 
     struct Dummy;
 
@@ -100,15 +63,13 @@ mod tests {
         }
     }
 
-    #[derive(Debug)]
-    struct DummyResult {
-        #[allow(dead_code)]
-        value: u8,
-    }
-
     #[test]
     fn test_marketstack_non_json_response() {
-        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("access_key", "123")])
+            .build()
+            .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "not json");
 
         api::ignore(Dummy).query(&client).unwrap()
@@ -116,7 +77,11 @@ mod tests {
 
     #[tokio::test]
     async fn test_marketstack_non_json_response_async() {
-        let endpoint = ExpectedUrl::builder().endpoint("dummy").build().unwrap();
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .add_query_params(&[("access_key", "123")])
+            .build()
+            .unwrap();
         let client = SingleTestClient::new_raw(endpoint, "not json");
 
         api::ignore(Dummy).query_async(&client).await.unwrap()
@@ -126,6 +91,7 @@ mod tests {
     fn test_marketstack_error_bad_json() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
+            .add_query_params(&[("access_key", "123")])
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
@@ -143,6 +109,7 @@ mod tests {
     fn test_marketstack_error_detection() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
+            .add_query_params(&[("access_key", "123")])
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
@@ -165,6 +132,7 @@ mod tests {
     fn test_marketstack_error_detection_unknown() {
         let endpoint = ExpectedUrl::builder()
             .endpoint("dummy")
+            .add_query_params(&[("access_key", "123")])
             .status(StatusCode::NOT_FOUND)
             .build()
             .unwrap();
@@ -64,8 +64,9 @@ use chrono::NaiveDate;
 use derive_builder::Builder;
 
 use crate::api::common::{Interval, SortOrder};
-use crate::api::paged::PaginationError;
+use crate::api::paged::{Pageable, PaginationError};
 use crate::api::{endpoint_prelude::*, ApiError};
+use crate::types::IntradayData;
 
 /// Query for `intraday` endpoint
 #[derive(Debug, Clone, Builder)]
@@ -108,6 +109,11 @@ impl<'a> Intraday<'a> {
     pub fn builder() -> IntradayBuilder<'a> {
         IntradayBuilder::default()
     }
+
+    /// Whether the endpoint is `intraday/latest`.
+    pub(crate) fn is_latest(&self) -> bool {
+        self.latest.is_some()
+    }
 }
 
 impl<'a> IntradayBuilder<'a> {
@@ -168,7 +174,7 @@ impl<'a> Endpoint for Intraday<'a> {
         }
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params
@@ -185,6 +191,12 @@ impl<'a> Endpoint for Intraday<'a> {
     }
 }
 
+impl<'a> Pageable for Intraday<'a> {}
+
+impl<'a> TypedEndpoint for Intraday<'a> {
+    type Response = IntradayData;
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -193,7 +205,7 @@ mod tests {
     use crate::api::common::{Interval, SortOrder};
     use crate::api::intraday::Intraday;
     use crate::api::{self, Query};
-    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
 
     #[test]
     fn intraday_defaults_are_sufficient() {
@@ -0,0 +1,182 @@
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::{HeaderMap, StatusCode};
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query};
+
+/// The data from an endpoint along with details of the HTTP exchange.
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    /// The data from the endpoint.
+    pub data: T,
+    /// The status code of the response.
+    pub status: StatusCode,
+    /// The headers of the response.
+    pub headers: HeaderMap,
+    /// How long the request took, including any retries made by the client.
+    pub elapsed: Duration,
+    /// The URL of the request, with the `access_key` redacted.
+    pub url: Url,
+}
+
+impl<T> Response<T> {
+    /// The value of a response header, if present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|value| value.to_str().ok())
+    }
+}
+
+/// A query modifier that returns the data from an endpoint along with response metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WithMeta<E> {
+    endpoint: E,
+}
+
+/// Return the data from an endpoint along with the status, headers and timing of the response.
+pub fn with_meta<E>(endpoint: E) -> WithMeta<E> {
+    WithMeta { endpoint }
+}
+
+fn into_response<T, E>(
+    rsp: http::Response<Bytes>,
+    url: &Url,
+    elapsed: Duration,
+) -> Result<Response<T>, ApiError<E>>
+where
+    T: DeserializeOwned,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let v = query::response_json(&rsp)?;
+    let data = serde_json::from_value::<T>(v).map_err(ApiError::data_type::<T>)?;
+    let (parts, _) = rsp.into_parts();
+
+    Ok(Response {
+        data,
+        status: parts.status,
+        headers: parts.headers,
+        elapsed,
+        url: query::redact(url),
+    })
+}
+
+impl<E, T, C> Query<Response<T>, C> for WithMeta<E>
+where
+    E: Endpoint,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Response<T>, ApiError<<C>::Error>> {
+        let url = query::endpoint_url(&self.endpoint, client)?;
+        let start = Instant::now();
+        let rsp = query::send(&self.endpoint, url.clone(), client)?;
+
+        into_response(rsp, &url, start.elapsed())
+    }
+}
+
+#[async_trait]
+impl<E, T, C> AsyncQuery<Response<T>, C> for WithMeta<E>
+where
+    E: Endpoint + Sync,
+    T: DeserializeOwned + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Response<T>, ApiError<C::Error>> {
+        let url = query::endpoint_url(&self.endpoint, client)?;
+        let start = Instant::now();
+        let rsp = query::send_async(&self.endpoint, url.clone(), client).await?;
+
+        into_response(rsp, &url, start.elapsed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, ErrorCode, Query};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+
+    struct Dummy;
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "dummy".into()
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct DummyResult {
+        value: u8,
+    }
+
+    fn client() -> SingleTestClient {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .response_headers(vec![("x-request-id", "abc123")])
+            .build()
+            .unwrap();
+        SingleTestClient::new_json(endpoint, &json!({"value": 0}))
+    }
+
+    #[test]
+    fn test_with_meta() {
+        let client = client();
+
+        let rsp: api::Response<DummyResult> = api::with_meta(Dummy).query(&client).unwrap();
+        assert_eq!(rsp.data.value, 0);
+        assert_eq!(rsp.status, StatusCode::OK);
+        assert_eq!(rsp.header("x-request-id"), Some("abc123"));
+        assert_eq!(
+            rsp.url.as_str(),
+            "https://marketstack.host.invalid/v1/dummy?access_key=REDACTED",
+        );
+    }
+
+    #[tokio::test]
+    async fn test_with_meta_async() {
+        let client = client();
+
+        let rsp: api::Response<DummyResult> =
+            api::with_meta(Dummy).query_async(&client).await.unwrap();
+        assert_eq!(rsp.data.value, 0);
+        assert_eq!(rsp.header("x-request-id"), Some("abc123"));
+        assert_eq!(rsp.url.query(), Some("access_key=REDACTED"));
+    }
+
+    #[test]
+    fn test_with_meta_error() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("dummy")
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "error": {
+                    "code": "rate_limit_reached",
+                    "message": "slow down",
+                },
+            }),
+        );
+
+        let err = api::with_meta(Dummy)
+            .query(&client)
+            .map(|_: api::Response<DummyResult>| ())
+            .unwrap_err();
+        assert_eq!(err.error_code(), Some(&ErrorCode::RateLimitReached));
+        assert!(matches!(err, ApiError::MarketstackError { .. }));
+    }
+}
@@ -2,10 +2,71 @@
 //!
 //! Pagination is done simply for Marketstack, but this allows setting
 //! page limits to have safety guarantees provided by the new-type pattern.
+//!
+//! Endpoints which support pagination may also be wrapped with [`paged`] to
+//! walk through every page of results, rather than setting `limit` and `offset`
+//! by hand.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use marketstack::api::{self, Query};
+//! use marketstack::api::eod::Eod;
+//! use marketstack::api::paged::Pagination;
+//! use marketstack::{Marketstack, EodDataItem};
+//!
+//! let client = Marketstack::new_insecure("api.marketstack.com", "private-token").unwrap();
+//!
+//! let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+//!
+//! // Fetch every row available for the symbol.
+//! let all: Vec<EodDataItem> = api::paged(endpoint.clone(), Pagination::All)
+//!     .query(&client)
+//!     .unwrap();
+//!
+//! // OR stop after a fixed number of rows.
+//! let some: Vec<EodDataItem> = api::paged(endpoint, Pagination::Limit(2500))
+//!     .query(&client)
+//!     .unwrap();
+//! ```
+//!
+//! With [`AsyncMarketstack`](crate::AsyncMarketstack), results may instead be consumed as a
+//! [`Stream`] which only requests the next page once the previous one has been used up.
+//!
+//! ```rust,no_run
+//! use std::pin::pin;
+//!
+//! use futures_util::TryStreamExt;
+//!
+//! use marketstack::api::{self, tickers::Tickers};
+//! use marketstack::api::paged::Pagination;
+//! use marketstack::{AsyncMarketstack, TickersDataItem};
+//!
+//! # async fn run() {
+//! let client = AsyncMarketstack::new_insecure("api.marketstack.com", "private-token")
+//!     .await
+//!     .unwrap();
+//!
+//! let endpoint = api::paged(Tickers::builder().build().unwrap(), Pagination::All);
+//! let mut tickers = pin!(endpoint.iter_async::<TickersDataItem, _>(&client));
+//!
+//! while let Some(ticker) = tickers.try_next().await.unwrap() {
+//!     println!("{}", ticker.symbol);
+//! }
+//! # }
+//! ```
 
+use async_trait::async_trait;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
 use thiserror::Error;
+use url::Url;
+
+use crate::api::{query, ApiError, AsyncClient, AsyncQuery, Client, Endpoint, Query, RestClient};
+use crate::types::Paginated;
 
-use crate::api::ApiError;
+/// The largest page size Marketstack accepts for the `limit` parameter.
+const MAX_PAGE_SIZE: usize = 1000;
 
 /// New-type implementation reflecting pagination limits.
 #[derive(Clone, Debug)]
@@ -14,7 +75,7 @@ pub struct PageLimit(pub u16);
 impl PageLimit {
     /// Construct PageLimit type with appropriate checks on valid bounds.
     pub fn new(limit: u16) -> Result<Self, ApiError<PaginationError>> {
-        if limit <= 1000 {
+        if limit as usize <= MAX_PAGE_SIZE {
             Ok(Self(limit))
         } else {
             Err(ApiError::Pagination {
@@ -31,11 +92,267 @@ pub enum PaginationError {
     /// Pagination exceeds the limit allowed by Marketstack.
     #[error("pagination exceeds limit error")]
     ExceedLimit,
+    /// The endpoint does not return paginated results.
+    #[error("endpoint `{}` is not paginated", endpoint)]
+    NotPageable {
+        /// The endpoint which was requested.
+        endpoint: String,
+    },
+}
+
+/// A marker trait for endpoints which Marketstack paginates with `limit` and `offset`.
+///
+/// Only endpoints whose response is a `{ pagination, data: [...] }` envelope should
+/// implement this trait.
+pub trait Pageable {
+    /// Whether this instance of the endpoint is paginated.
+    ///
+    /// Endpoints which are only paginated for some of their parameters should override this.
+    fn is_pageable(&self) -> bool {
+        true
+    }
+}
+
+/// Pagination options for Marketstack.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Pagination {
+    /// Return all results.
+    #[default]
+    All,
+    /// Limit to a number of results.
+    Limit(usize),
+}
+
+impl Pagination {
+    /// The page size to request given the number of results already collected.
+    fn page_limit(self, collected: usize) -> usize {
+        match self {
+            Pagination::All => MAX_PAGE_SIZE,
+            Pagination::Limit(limit) => MAX_PAGE_SIZE.min(limit.saturating_sub(collected)),
+        }
+    }
+
+    /// Whether enough results have been collected.
+    fn is_satisfied(self, collected: usize) -> bool {
+        match self {
+            Pagination::All => false,
+            Pagination::Limit(limit) => collected >= limit,
+        }
+    }
+}
+
+/// A query modifier that paginates an endpoint.
+#[derive(Debug, Clone)]
+pub struct Paged<E> {
+    endpoint: E,
+    pagination: Pagination,
+}
+
+/// Collect data from a paged endpoint.
+///
+/// Any `limit` set on the endpoint is replaced by the page size chosen here. An `offset`
+/// set on the endpoint is used as the starting point.
+pub fn paged<E>(endpoint: E, pagination: Pagination) -> Paged<E> {
+    Paged {
+        endpoint,
+        pagination,
+    }
+}
+
+impl<E> Paged<E>
+where
+    E: Endpoint + Pageable,
+{
+    /// Build the URL shared by every page, returning it with the starting offset.
+    ///
+    /// The `limit` and `offset` parameters are stripped so they may be set per page.
+    fn base_url<C>(&self, client: &C) -> Result<(Url, usize), ApiError<C::Error>>
+    where
+        C: RestClient,
+    {
+        if !self.endpoint.is_pageable() {
+            return Err(ApiError::Pagination {
+                source: PaginationError::NotPageable {
+                    endpoint: self.endpoint.endpoint().into_owned(),
+                },
+            });
+        }
+
+        let mut url = query::endpoint_url(&self.endpoint, client)?;
+
+        let mut start = 0;
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .filter_map(|(key, value)| match key.as_ref() {
+                "limit" => None,
+                "offset" => {
+                    start = value.parse().unwrap_or(0);
+                    None
+                }
+                _ => Some((key.into_owned(), value.into_owned())),
+            })
+            .collect();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+
+        Ok((url, start))
+    }
+}
+
+/// The URL of a single page.
+fn page_url(url: &Url, offset: usize, limit: usize) -> Url {
+    let mut page_url = url.clone();
+    page_url
+        .query_pairs_mut()
+        .append_pair("limit", &limit.to_string())
+        .append_pair("offset", &offset.to_string());
+    page_url
+}
+
+fn process_page<T, C>(rsp: http::Response<bytes::Bytes>) -> Result<Paginated<T>, ApiError<C::Error>>
+where
+    T: DeserializeOwned,
+    C: RestClient,
+{
+    let v = query::response_json(&rsp)?;
+
+    serde_json::from_value::<Paginated<T>>(v).map_err(ApiError::data_type::<Vec<T>>)
+}
+
+impl<E, T, C> Query<Vec<T>, C> for Paged<E>
+where
+    E: Endpoint + Pageable,
+    T: DeserializeOwned,
+    C: Client,
+{
+    fn query(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        let (url, mut offset) = self.base_url(client)?;
+        let mut results = Vec::new();
+
+        while !self.pagination.is_satisfied(results.len()) {
+            let limit = self.pagination.page_limit(results.len());
+            let rsp = query::send(&self.endpoint, page_url(&url, offset, limit), client)?;
+            let page = process_page::<T, C>(rsp)?;
+
+            let last = !page.has_more();
+            offset += page.data.len();
+            results.extend(page.data);
+            if last {
+                break;
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+/// Where a stream of pages has got to.
+struct Cursor {
+    url: Url,
+    offset: usize,
+    collected: usize,
+}
+
+impl<E> Paged<E>
+where
+    E: Endpoint + Pageable + Sync,
+{
+    /// Stream the results one page at a time.
+    ///
+    /// Each page is only requested once the stream is polled for it, so dropping the stream
+    /// stops any further requests. The stream ends after yielding the first error.
+    pub fn pages_async<'a, T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> impl Stream<Item = Result<Vec<T>, ApiError<C::Error>>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+        C: AsyncClient + Sync,
+    {
+        let cursor = self.base_url(client).map(|(url, offset)| Cursor {
+            url,
+            offset,
+            collected: 0,
+        });
+
+        stream::unfold(Some(cursor), move |cursor| async move {
+            let mut cursor = match cursor? {
+                Ok(cursor) => cursor,
+                Err(err) => return Some((Err(err), None)),
+            };
+            if self.pagination.is_satisfied(cursor.collected) {
+                return None;
+            }
+
+            let limit = self.pagination.page_limit(cursor.collected);
+            let page = match self.page_async::<T, C>(client, &cursor, limit).await {
+                Ok(page) => page,
+                Err(err) => return Some((Err(err), None)),
+            };
+
+            let last = !page.has_more();
+            cursor.offset += page.data.len();
+            cursor.collected += page.data.len();
+            let next = if last { None } else { Some(Ok(cursor)) };
+
+            Some((Ok(page.data), next))
+        })
+    }
+
+    /// Stream the results one item at a time.
+    ///
+    /// Pages are requested lazily as with [`Paged::pages_async`].
+    pub fn iter_async<'a, T, C>(
+        &'a self,
+        client: &'a C,
+    ) -> impl Stream<Item = Result<T, ApiError<C::Error>>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+        C: AsyncClient + Sync,
+    {
+        self.pages_async(client)
+            .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+            .try_flatten()
+    }
+
+    async fn page_async<T, C>(
+        &self,
+        client: &C,
+        cursor: &Cursor,
+        limit: usize,
+    ) -> Result<Paginated<T>, ApiError<C::Error>>
+    where
+        T: DeserializeOwned,
+        C: AsyncClient + Sync,
+    {
+        let url = page_url(&cursor.url, cursor.offset, limit);
+        let rsp = query::send_async(&self.endpoint, url, client).await?;
+        process_page::<T, C>(rsp)
+    }
+}
+
+#[async_trait]
+impl<E, T, C> AsyncQuery<Vec<T>, C> for Paged<E>
+where
+    E: Endpoint + Pageable + Sync,
+    T: DeserializeOwned + Send + 'static,
+    C: AsyncClient + Sync,
+{
+    async fn query_async(&self, client: &C) -> Result<Vec<T>, ApiError<C::Error>> {
+        self.pages_async(client).try_concat().await
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::PageLimit;
+    use futures_util::{StreamExt, TryStreamExt};
+    use http::StatusCode;
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::paged::{PageLimit, Pageable, Pagination};
+    use crate::api::{self, ApiError, AsyncQuery, Query};
+    use crate::testing::{ExpectedUrl, PagedTestClient, SingleTestClient};
 
     #[test]
     fn test_new() {
@@ -56,4 +373,239 @@ mod tests {
             "pagination error: pagination exceeds limit error"
         );
     }
+
+    #[derive(Debug, Default)]
+    struct Dummy {
+        offset: Option<u64>,
+    }
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            "paged_dummy".into()
+        }
+
+        fn parameters(&self) -> QueryParams<'_> {
+            let mut params = QueryParams::default();
+
+            params.push("limit", 5_u64).push_opt("offset", self.offset);
+
+            params
+        }
+    }
+
+    impl Pageable for Dummy {}
+
+    #[derive(Debug, Deserialize, Serialize, PartialEq, Eq)]
+    struct DummyResult {
+        value: usize,
+    }
+
+    fn dummy_client(count: usize) -> PagedTestClient<DummyResult> {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("access_key", "123")])
+            .paginated(true)
+            .build()
+            .unwrap();
+
+        PagedTestClient::new_raw(endpoint, (0..count).map(|value| DummyResult { value }))
+    }
+
+    #[test]
+    fn pagination_default() {
+        assert_eq!(Pagination::default(), Pagination::All);
+    }
+
+    #[test]
+    fn test_paged_all() {
+        let client = dummy_client(2500);
+
+        let res: Vec<DummyResult> = api::paged(Dummy::default(), Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.len(), 2500);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i);
+        }
+    }
+
+    #[test]
+    fn test_paged_all_exact_pages() {
+        let client = dummy_client(2000);
+
+        let res: Vec<DummyResult> = api::paged(Dummy::default(), Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.len(), 2000);
+    }
+
+    #[test]
+    fn test_paged_empty() {
+        let client = dummy_client(0);
+
+        let res: Vec<DummyResult> = api::paged(Dummy::default(), Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert!(res.is_empty());
+    }
+
+    #[test]
+    fn test_paged_limit() {
+        let client = dummy_client(2500);
+
+        let res: Vec<DummyResult> = api::paged(Dummy::default(), Pagination::Limit(1500))
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.len(), 1500);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i);
+        }
+    }
+
+    #[test]
+    fn test_paged_limit_over_total() {
+        let client = dummy_client(30);
+
+        let res: Vec<DummyResult> = api::paged(Dummy::default(), Pagination::Limit(100))
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.len(), 30);
+    }
+
+    #[test]
+    fn test_paged_offset() {
+        let client = dummy_client(1200);
+
+        let endpoint = Dummy { offset: Some(150) };
+        let res: Vec<DummyResult> = api::paged(endpoint, Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(res.len(), 1050);
+        assert_eq!(res[0].value, 150);
+        assert_eq!(res[1049].value, 1199);
+    }
+
+    #[tokio::test]
+    async fn test_paged_all_async() {
+        let client = dummy_client(2500);
+
+        let res: Vec<DummyResult> = api::paged(Dummy::default(), Pagination::All)
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 2500);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paged_limit_async() {
+        let client = dummy_client(2500);
+
+        let res: Vec<DummyResult> = api::paged(Dummy::default(), Pagination::Limit(5))
+            .query_async(&client)
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_pages_async() {
+        let client = dummy_client(2500);
+
+        let endpoint = api::paged(Dummy::default(), Pagination::All);
+        let pages: Vec<Vec<DummyResult>> =
+            endpoint.pages_async(&client).try_collect().await.unwrap();
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[0].len(), 1000);
+        assert_eq!(pages[1].len(), 1000);
+        assert_eq!(pages[2].len(), 500);
+        assert_eq!(pages[2][0].value, 2000);
+    }
+
+    #[tokio::test]
+    async fn test_iter_async() {
+        let client = dummy_client(2500);
+
+        let endpoint = api::paged(Dummy::default(), Pagination::Limit(1200));
+        let res: Vec<DummyResult> = endpoint.iter_async(&client).try_collect().await.unwrap();
+        assert_eq!(res.len(), 1200);
+        for (i, value) in res.iter().enumerate() {
+            assert_eq!(value.value, i);
+        }
+        assert_eq!(client.request_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_iter_async_early_termination() {
+        let client = dummy_client(2500);
+
+        let endpoint = api::paged(Dummy::default(), Pagination::All);
+        let res: Vec<DummyResult> = endpoint
+            .iter_async(&client)
+            .take(3)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(res.len(), 3);
+        assert_eq!(client.request_count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_pages_async_error() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("access_key", "123")])
+            .paginated(true)
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let endpoint = api::paged(Dummy::default(), Pagination::All);
+        let pages: Vec<Result<Vec<DummyResult>, _>> = endpoint.pages_async(&client).collect().await;
+        assert_eq!(pages.len(), 1);
+        let err = pages.into_iter().next().unwrap().unwrap_err();
+        if let ApiError::Marketstack { msg } = err {
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn test_paged_error_detection() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("paged_dummy")
+            .add_query_params(&[("access_key", "123")])
+            .paginated(true)
+            .status(StatusCode::NOT_FOUND)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "message": "dummy error message",
+            }),
+        );
+
+        let res: Result<Vec<DummyResult>, _> =
+            api::paged(Dummy::default(), Pagination::All).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::Marketstack { msg } = err {
+            assert_eq!(msg, "dummy error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
 }
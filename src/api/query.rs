@@ -1,8 +1,12 @@
+use std::error::Error;
+
 use async_trait::async_trait;
-use http::Uri;
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::{header, Request, Response, Uri};
 use url::Url;
 
-use crate::api::{ApiError, AsyncClient, Client};
+use crate::api::{ApiError, AsyncClient, Client, Endpoint, RestClient};
 
 pub fn url_to_http_uri(url: Url) -> Uri {
     url.as_str()
@@ -10,6 +14,128 @@ pub fn url_to_http_uri(url: Url) -> Uri {
         .expect("failed to parse a url::Url as an http::Uri")
 }
 
+/// The value which replaces the `access_key` in URLs which are shown or stored.
+pub(crate) const REDACTED: &str = "REDACTED";
+
+/// A copy of `url` with the value of the `access_key` replaced by [`REDACTED`].
+pub(crate) fn redact(url: &Url) -> Url {
+    let mut url = url.clone();
+    if !url.query_pairs().any(|(name, _)| name == "access_key") {
+        return url;
+    }
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .map(|(name, value)| {
+            if name == "access_key" {
+                (name.into_owned(), REDACTED.into())
+            } else {
+                (name.into_owned(), value.into_owned())
+            }
+        })
+        .collect();
+    url.query_pairs_mut().clear().extend_pairs(pairs);
+    url
+}
+
+// Every query goes through the functions below to talk to Marketstack: `endpoint_url` decides
+// where a request goes, `send` or `send_async` make it and `check_status` or `response_json`
+// interpret the response.
+
+/// The URL for an endpoint, with its parameters and the `access_key` of the client.
+pub(crate) fn endpoint_url<E, C>(endpoint: &E, client: &C) -> Result<Url, ApiError<C::Error>>
+where
+    E: Endpoint + ?Sized,
+    C: RestClient,
+{
+    let token = client.get_auth().ok_or_else(ApiError::auth_error)?;
+    let mut url = if let Some(version) = endpoint.api_version() {
+        client.versioned_rest_endpoint(version, &endpoint.endpoint())?
+    } else {
+        client.rest_endpoint(&endpoint.endpoint())?
+    };
+    // Mutate every query with parameters that pushes access_key by default.
+    endpoint
+        .parameters()
+        .push("access_key", token)
+        .add_to_url(&mut url);
+    Ok(url)
+}
+
+fn request<E, C>(endpoint: &E, url: Url) -> Result<(RequestBuilder, Vec<u8>), ApiError<C::Error>>
+where
+    E: Endpoint + ?Sized,
+    C: RestClient,
+{
+    let req = Request::builder()
+        .method(endpoint.method())
+        .uri(url_to_http_uri(url));
+    if let Some((mime, data)) = endpoint.body()? {
+        Ok((req.header(header::CONTENT_TYPE, mime), data))
+    } else {
+        Ok((req, Vec::new()))
+    }
+}
+
+/// Send the request for an endpoint to `url`.
+pub(crate) fn send<E, C>(
+    endpoint: &E,
+    url: Url,
+    client: &C,
+) -> Result<Response<Bytes>, ApiError<C::Error>>
+where
+    E: Endpoint + ?Sized,
+    C: Client,
+{
+    let (req, data) = request::<E, C>(endpoint, url)?;
+    client.rest(req, data)
+}
+
+/// Send the request for an endpoint to `url` asynchronously.
+pub(crate) async fn send_async<E, C>(
+    endpoint: &E,
+    url: Url,
+    client: &C,
+) -> Result<Response<Bytes>, ApiError<C::Error>>
+where
+    E: Endpoint + Sync + ?Sized,
+    C: AsyncClient + Sync,
+{
+    let (req, data) = request::<E, C>(endpoint, url)?;
+    client.rest_async(req, data).await
+}
+
+/// Turn an unsuccessful response into the error reported by Marketstack.
+pub(crate) fn check_status<E>(rsp: &Response<Bytes>) -> Result<(), ApiError<E>>
+where
+    E: Error + Send + Sync + 'static,
+{
+    if rsp.status().is_success() {
+        return Ok(());
+    }
+    let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+        v
+    } else {
+        return Err(ApiError::server_error(rsp.status(), rsp.body()));
+    };
+    Err(ApiError::from_marketstack(rsp.status(), v))
+}
+
+/// The JSON body of a successful response.
+pub(crate) fn response_json<E>(rsp: &Response<Bytes>) -> Result<serde_json::Value, ApiError<E>>
+where
+    E: Error + Send + Sync + 'static,
+{
+    let v = if let Ok(v) = serde_json::from_slice(rsp.body()) {
+        v
+    } else {
+        return Err(ApiError::server_error(rsp.status(), rsp.body()));
+    };
+    if !rsp.status().is_success() {
+        return Err(ApiError::from_marketstack(rsp.status(), v));
+    }
+    Ok(v)
+}
+
 /// A trait which represents a query which may be made to a Marketstack client.
 pub trait Query<T, C>
 where
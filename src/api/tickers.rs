@@ -6,9 +6,11 @@ use derive_builder::Builder;
 
 use crate::api::dividends::Dividends;
 use crate::api::eod::Eod;
-use crate::api::paged::PaginationError;
+use crate::api::intraday::Intraday;
+use crate::api::paged::{Pageable, PaginationError};
 use crate::api::splits::Splits;
 use crate::api::{endpoint_prelude::*, ApiError};
+use crate::types::TickersResponse;
 
 /// Base for `tickers`.
 #[derive(Debug, Builder, Clone)]
@@ -38,6 +40,9 @@ pub struct Tickers<'a> {
     /// `Dividends` struct being built, and held by the `Tickers` struct.
     #[builder(setter(into), default)]
     dividends: Option<Dividends<'a>>,
+    /// `Intraday` struct being built, and held by the `Tickers` struct.
+    #[builder(setter(into), default)]
+    intraday: Option<Intraday<'a>>,
 }
 
 impl<'a> Tickers<'a> {
@@ -67,12 +72,15 @@ impl<'a> Endpoint for Tickers<'a> {
             if let Some(dividends) = &self.dividends {
                 endpoint.push_str(&format!("/{}", dividends.endpoint().as_ref()));
             }
+            if let Some(intraday) = &self.intraday {
+                endpoint.push_str(&format!("/{}", intraday.endpoint().as_ref()));
+            }
         }
 
         endpoint.into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         // NOTE: Not the most ergonomic way I want to go about this, but its okay for now since
@@ -87,6 +95,9 @@ impl<'a> Endpoint for Tickers<'a> {
         if let Some(dividends) = &self.dividends {
             params = dividends.parameters().clone();
         }
+        if let Some(intraday) = &self.intraday {
+            params = intraday.parameters().clone();
+        }
 
         // Push params from the `tickers` endpoint.
         params
@@ -99,6 +110,45 @@ impl<'a> Endpoint for Tickers<'a> {
     }
 }
 
+impl<'a> Pageable for Tickers<'a> {
+    // The listing, `splits` and `dividends` are paginated arrays; the ticker itself is a single
+    // object and `eod` and `intraday` wrap their data in an object.
+    fn is_pageable(&self) -> bool {
+        self.ticker.is_none() || self.splits.is_some() || self.dividends.is_some()
+    }
+}
+
+impl<'a> TypedEndpoint for Tickers<'a> {
+    type Response = TickersResponse;
+
+    fn decode(&self, value: serde_json::Value) -> Result<TickersResponse, serde_json::Error> {
+        // Nested endpoints are only requested along with a ticker.
+        if self.ticker.is_none() {
+            return serde_json::from_value(value).map(TickersResponse::List);
+        }
+
+        if let Some(eod) = &self.eod {
+            if eod.is_single_day() {
+                serde_json::from_value(value).map(TickersResponse::EodDay)
+            } else {
+                serde_json::from_value(value).map(TickersResponse::Eod)
+            }
+        } else if self.splits.is_some() {
+            serde_json::from_value(value).map(TickersResponse::Splits)
+        } else if self.dividends.is_some() {
+            serde_json::from_value(value).map(TickersResponse::Dividends)
+        } else if let Some(intraday) = &self.intraday {
+            if intraday.is_latest() {
+                serde_json::from_value(value).map(TickersResponse::IntradayLatest)
+            } else {
+                serde_json::from_value(value).map(TickersResponse::Intraday)
+            }
+        } else {
+            serde_json::from_value(value).map(TickersResponse::Ticker)
+        }
+    }
+}
+
 impl<'a> TickersBuilder<'a> {
     /// Limit the number of results returned.
     pub fn limit(&mut self, limit: u16) -> Result<&mut Self, ApiError<PaginationError>> {
@@ -113,10 +163,11 @@ impl<'a> TickersBuilder<'a> {
             self.eod.is_some(),
             self.splits.is_some(),
             self.dividends.is_some(),
+            self.intraday.is_some(),
         ];
         let count = active_fields.iter().filter(|x| **x).count();
         if count > 1 {
-            Err("Invalid combinations of `eod`, `splits` or `dividends`".into())
+            Err("Invalid combinations of `eod`, `splits`, `dividends` or `intraday`".into())
         } else {
             Ok(())
         }
@@ -128,13 +179,17 @@ mod tests {
 
     use chrono::NaiveDate;
 
+    use crate::api::common::Interval;
     use crate::api::common::SortOrder;
     use crate::api::dividends::Dividends;
     use crate::api::eod::Eod;
+    use crate::api::intraday::Intraday;
+    use crate::api::paged::{Pagination, PaginationError};
     use crate::api::splits::Splits;
     use crate::api::tickers::Tickers;
-    use crate::api::{self, Query};
-    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::api::{self, ApiError, Query};
+    use crate::testing::{ExpectedUrl, MockClient, PagedTestClient, SingleTestClient};
+    use crate::types::{EodDataItem, SplitsDataItem};
 
     #[test]
     fn tickers_defaults_are_sufficient() {
@@ -262,6 +317,78 @@ mod tests {
         api::ignore(endpoint).query(&client).unwrap();
     }
 
+    #[test]
+    fn tickers_intraday() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tickers/AAPL/intraday")
+            .add_query_params(&[("interval", "1hour"), ("limit", "5")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Tickers::builder()
+            .ticker("AAPL")
+            .intraday(
+                Intraday::builder()
+                    .interval(Interval::OneHour)
+                    .limit(5)
+                    .unwrap()
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn tickers_intraday_latest() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tickers/AAPL/intraday/latest")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Tickers::builder()
+            .ticker("AAPL")
+            .intraday(Intraday::builder().latest(true).build().unwrap())
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn tickers_intraday_date() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tickers/AAPL/intraday/2023-09-27")
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Tickers::builder()
+            .ticker("AAPL")
+            .intraday(
+                Intraday::builder()
+                    .date(NaiveDate::from_ymd_opt(2023, 9, 27).unwrap())
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn tickers_intraday_validator() {
+        let endpoint = Tickers::builder()
+            .ticker("AAPL")
+            .eod(Eod::builder().build().unwrap())
+            .intraday(Intraday::builder().build().unwrap())
+            .build();
+        assert!(endpoint.is_err());
+        assert!(endpoint.err().unwrap().to_string().contains("intraday"));
+    }
+
     #[test]
     fn tickers_validator() {
         let endpoint = Tickers::builder()
@@ -271,4 +398,52 @@ mod tests {
         assert!(endpoint.is_err());
         assert!(endpoint.err().unwrap().to_string().contains("Invalid"));
     }
+
+    #[test]
+    fn tickers_nested_is_not_pageable() {
+        // No request may be sent.
+        let client = MockClient::new();
+
+        let endpoint = Tickers::builder()
+            .ticker("AAPL")
+            .eod(Eod::builder().build().unwrap())
+            .build()
+            .unwrap();
+        let res: Result<Vec<EodDataItem>, _> = api::paged(endpoint, Pagination::All).query(&client);
+        let err = res.unwrap_err();
+        if let ApiError::Pagination {
+            source: PaginationError::NotPageable { endpoint },
+        } = err
+        {
+            assert_eq!(endpoint, "tickers/AAPL/eod");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn tickers_splits_paged() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tickers/AAPL/splits")
+            .paginated(true)
+            .build()
+            .unwrap();
+        let splits = (0..250).map(|_| SplitsDataItem {
+            date: NaiveDate::from_ymd_opt(2023, 9, 28).unwrap(),
+            split_factor: 2.0,
+            symbol: "AAPL".into(),
+        });
+        let client = PagedTestClient::new_raw(endpoint, splits);
+
+        let endpoint = Tickers::builder()
+            .ticker("AAPL")
+            .splits(Splits::builder().build().unwrap())
+            .build()
+            .unwrap();
+        let splits: Vec<SplitsDataItem> = api::paged(endpoint, Pagination::All)
+            .query(&client)
+            .unwrap();
+        assert_eq!(splits.len(), 250);
+        assert_eq!(client.request_count(), 1);
+    }
 }
@@ -4,8 +4,9 @@
 
 use derive_builder::Builder;
 
-use crate::api::paged::PaginationError;
+use crate::api::paged::{Pageable, PaginationError};
 use crate::api::{endpoint_prelude::*, ApiError};
+use crate::types::TimezonesData;
 
 /// Query for `timezones`.
 #[derive(Debug, Clone, Builder)]
@@ -44,7 +45,7 @@ impl Endpoint for Timezones {
         "timezones".into()
     }
 
-    fn parameters(&self) -> QueryParams {
+    fn parameters(&self) -> QueryParams<'_> {
         let mut params = QueryParams::default();
 
         params
@@ -55,12 +56,18 @@ impl Endpoint for Timezones {
     }
 }
 
+impl Pageable for Timezones {}
+
+impl TypedEndpoint for Timezones {
+    type Response = TimezonesData;
+}
+
 #[cfg(test)]
 mod tests {
 
     use crate::api::timezones::Timezones;
     use crate::api::{self, Query};
-    use crate::test::client::{ExpectedUrl, SingleTestClient};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
 
     #[test]
     fn timezones_defaults_are_sufficient() {
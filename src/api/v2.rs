@@ -0,0 +1,35 @@
+//! Endpoints of version 2 of the Marketstack API.
+//!
+//! Version 2 reshapes some endpoints of version 1 and adds new ones. The endpoints in this
+//! module ask for version 2 through [`Endpoint::api_version`](crate::api::Endpoint::api_version),
+//! whichever [`ApiVersion`](crate::ApiVersion) the client was built for, so a single client can
+//! serve both versions.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use marketstack::api::eod;
+//! use marketstack::api::v2::eod::Eod;
+//! use marketstack::api::TypedEndpoint;
+//! use marketstack::Marketstack;
+//!
+//! let client = Marketstack::new("api.marketstack.com", "private-token").unwrap();
+//!
+//! let endpoint = Eod::builder()
+//!     .eod(eod::Eod::builder().symbol("AAPL").build().unwrap())
+//!     .build()
+//!     .unwrap();
+//! let eod_data = endpoint.fetch(&client).unwrap();
+//!
+//! assert!(eod_data.iter().all(|eod| eod.asset_type.is_some()));
+//! ```
+
+pub mod bond;
+pub mod bondlist;
+pub mod commodities;
+pub mod eod;
+pub mod etf;
+pub mod indices;
+pub mod stockprice;
+pub mod tickerinfo;
+pub mod tickerslist;
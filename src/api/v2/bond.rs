@@ -0,0 +1,151 @@
+//! Implementation of the `bond/[country]` API endpoint.
+//!
+//! This endpoint is used to lookup the government bond yields of a country, by maturity. It is
+//! part of version 2 of the Marketstack API.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::types::v2::BondData;
+
+/// Query for `bond/[country]`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct Bond<'a> {
+    /// Country to get the bond yields of, as listed by `bondlist`. Example: `united states`
+    #[builder(setter(into))]
+    country: Cow<'a, str>,
+}
+
+impl<'a> Bond<'a> {
+    /// Create a builder for this endpoint.
+    pub fn builder() -> BondBuilder<'a> {
+        BondBuilder::default()
+    }
+}
+
+impl<'a> BondBuilder<'a> {
+    /// Check that the country can be used as a path segment.
+    fn validate(&self) -> Result<(), String> {
+        match &self.country {
+            Some(country) if country.trim().is_empty() => Err("`country` must not be empty".into()),
+            Some(country) if country.contains(['/', '\\', '?', '#', '%']) => {
+                Err("`country` must not contain `/`, `\\`, `?`, `#` or `%`".into())
+            }
+            // Dot segments would be resolved away, leading to another endpoint.
+            Some(country) if country == "." || country == ".." => {
+                Err("`country` must not be `.` or `..`".into())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a> Endpoint for Bond<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        format!("bond/{}", self.country).into()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+}
+
+impl<'a> TypedEndpoint for Bond<'a> {
+    type Response = BondData;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::v2::bond::Bond;
+    use crate::api::{self, Query, TypedEndpoint};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+    use crate::ApiVersion;
+
+    #[test]
+    fn bond_country_is_required() {
+        let err = Bond::builder().build().unwrap_err();
+        assert_eq!(err.to_string(), "`country` must be initialized");
+    }
+
+    #[test]
+    fn bond_country_is_not_empty() {
+        let err = Bond::builder().country(" ").build().unwrap_err();
+        assert_eq!(err.to_string(), "`country` must not be empty");
+    }
+
+    #[test]
+    fn bond_country_is_a_path_segment() {
+        for country in ["../eod", "..\\eod", "%2e%2e", "germany?x=1"] {
+            let err = Bond::builder().country(country).build().unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "`country` must not contain `/`, `\\`, `?`, `#` or `%`",
+            );
+        }
+
+        for country in [".", ".."] {
+            let err = Bond::builder().country(country).build().unwrap_err();
+            assert_eq!(err.to_string(), "`country` must not be `.` or `..`");
+        }
+    }
+
+    #[test]
+    fn bond_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bond/united%20states")
+            .api_version(ApiVersion::V2)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Bond::builder().country("united states").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn bond_fetch() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bond/germany")
+            .api_version(ApiVersion::V2)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "data": [
+                    {
+                        "region": "europe",
+                        "country": "germany",
+                        "type": "10Y",
+                        "yield": "2.874",
+                        "price_change_day": "-0.011",
+                        "percentage_week": "0.40%",
+                        "percentage_month": "16.17%",
+                        "percentage_year": "17.23%",
+                        "date": "2025-03-14",
+                    },
+                    {
+                        "region": "europe",
+                        "country": "germany",
+                        "type": "2Y",
+                        "yield": 2.212,
+                        "date": "2025-03-14",
+                    },
+                ],
+            }),
+        );
+
+        let endpoint = Bond::builder().country("germany").build().unwrap();
+        let bonds = endpoint.fetch(&client).unwrap();
+        assert_eq!(bonds.data[0].maturity, "10Y");
+        assert_eq!(bonds.data[0].yield_value, 2.874);
+        assert_eq!(bonds.data[1].yield_value, 2.212);
+    }
+}
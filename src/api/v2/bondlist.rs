@@ -0,0 +1,165 @@
+//! Implementation of the `bondlist` API endpoint.
+//!
+//! This endpoint is used to lookup the countries with government bond data in Marketstack. It is
+//! part of version 2 of the Marketstack API.
+
+use derive_builder::Builder;
+
+use crate::api::paged::{Pageable, PaginationError};
+use crate::api::{endpoint_prelude::*, ApiError};
+use crate::types::v2::BondListData;
+
+/// Query for `bondlist`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct BondList {
+    /// Pagination limit for API request.
+    #[builder(setter(name = "_limit"), default)]
+    limit: Option<PageLimit>,
+    /// Pagination offset value for API request.
+    #[builder(default)]
+    offset: Option<u64>,
+}
+
+impl BondList {
+    /// Create a builder for this endpoint.
+    pub fn builder() -> BondListBuilder {
+        BondListBuilder::default()
+    }
+}
+
+impl BondListBuilder {
+    /// Limit the number of results returned.
+    pub fn limit(&mut self, limit: u16) -> Result<&mut Self, ApiError<PaginationError>> {
+        let new = self;
+        new.limit = Some(Some(PageLimit::new(limit)?));
+        Ok(new)
+    }
+}
+
+impl Endpoint for BondList {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "bondlist".into()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("limit", self.limit.clone())
+            .push_opt("offset", self.offset);
+
+        params
+    }
+}
+
+impl Pageable for BondList {}
+
+impl TypedEndpoint for BondList {
+    type Response = BondListData;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::v2::bondlist::BondList;
+    use crate::api::{self, Query, TypedEndpoint};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+    use crate::ApiVersion;
+
+    #[test]
+    fn bondlist_defaults_are_sufficient() {
+        BondList::builder().build().unwrap();
+    }
+
+    #[test]
+    fn bondlist_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bondlist")
+            .api_version(ApiVersion::V2)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BondList::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn bondlist_limit() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bondlist")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("limit", "50")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BondList::builder().limit(50).unwrap().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn bondlist_over_limit() {
+        assert!(BondList::builder().limit(9999).is_err());
+    }
+
+    #[test]
+    fn bondlist_offset() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bondlist")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("offset", "2")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = BondList::builder().offset(2).build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn bondlist_fetch() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("bondlist")
+            .api_version(ApiVersion::V2)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "pagination": {
+                    "limit": 100,
+                    "offset": 0,
+                    "count": 2,
+                    "total": 2,
+                },
+                "data": [
+                    {
+                        "region": "europe",
+                        "country": "germany",
+                    },
+                    {
+                        "country": "united states",
+                    },
+                ],
+            }),
+        );
+
+        let endpoint = BondList::builder().build().unwrap();
+        let countries = endpoint.fetch(&client).unwrap();
+        assert_eq!(countries.pagination.total, 2);
+        assert_eq!(countries.data[0].country, "germany");
+        assert_eq!(countries.data[0].region.as_deref(), Some("europe"));
+        assert_eq!(countries.data[1].region, None);
+    }
+}
@@ -0,0 +1,361 @@
+//! Implemented endpoints for `commodities` and `commoditieshistory`.
+//!
+//! Both endpoints are part of version 2 of the Marketstack API and query it whichever
+//! [`ApiVersion`](crate::ApiVersion) the client was built for.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use marketstack::api::v2::commodities::Commodities;
+//! use marketstack::api::TypedEndpoint;
+//! use marketstack::Marketstack;
+//!
+//! let client = Marketstack::new("api.marketstack.com", "private-token").unwrap();
+//!
+//! // Create the `commodities` endpoint for the latest price.
+//! let endpoint = Commodities::builder().commodity_name("aluminum").build().unwrap();
+//! let commodities = endpoint.fetch(&client).unwrap();
+//!
+//! assert!(commodities.data.iter().all(|c| c.commodity_name == "aluminum"));
+//! ```
+//!
+//! # Commodity History
+//!
+//! ```rust,no_run
+//! use chrono::NaiveDate;
+//!
+//! use marketstack::api::common::Frequency;
+//! use marketstack::api::v2::commodities::CommoditiesHistory;
+//! use marketstack::api::TypedEndpoint;
+//! use marketstack::Marketstack;
+//!
+//! let client = Marketstack::new("api.marketstack.com", "private-token").unwrap();
+//!
+//! // Create the `commoditieshistory` endpoint for monthly prices in 2024.
+//! let endpoint = CommoditiesHistory::builder()
+//!     .commodity_name("aluminum")
+//!     .date_from(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+//!     .date_to(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+//!     .frequency(Frequency::Monthly)
+//!     .build()
+//!     .unwrap();
+//! let history = endpoint.fetch(&client).unwrap();
+//!
+//! assert_eq!(history.data.len(), 12);
+//! ```
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common::{self, Frequency, SortOrder};
+use crate::api::paged::{Pageable, PaginationError};
+use crate::api::{endpoint_prelude::*, ApiError};
+use crate::types::v2::{CommoditiesData, CommoditiesHistoryData};
+
+/// Query for `commodities`.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct Commodities<'a> {
+    /// Name of the commodity, e.g. `aluminum`.
+    #[builder(setter(into))]
+    commodity_name: Cow<'a, str>,
+}
+
+impl<'a> Commodities<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CommoditiesBuilder<'a> {
+        CommoditiesBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Commodities<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "commodities".into()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params.push("commodity_name", &self.commodity_name);
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for Commodities<'a> {
+    type Response = CommoditiesData;
+}
+
+/// Query for `commoditieshistory`.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct CommoditiesHistory<'a> {
+    /// Name of the commodity, e.g. `aluminum`.
+    #[builder(setter(into))]
+    commodity_name: Cow<'a, str>,
+    /// Date to query prices from.
+    #[builder(default)]
+    date_from: Option<NaiveDate>,
+    /// Date to query prices to.
+    #[builder(default)]
+    date_to: Option<NaiveDate>,
+    /// The frequency of the prices.
+    #[builder(default)]
+    frequency: Option<Frequency>,
+    /// The sort order for the return results.
+    #[builder(default)]
+    sort: Option<SortOrder>,
+    /// Pagination limit for API request.
+    #[builder(setter(name = "_limit"), default)]
+    limit: Option<PageLimit>,
+    /// Pagination offset value for API request.
+    #[builder(default)]
+    offset: Option<u64>,
+}
+
+impl<'a> CommoditiesHistory<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> CommoditiesHistoryBuilder<'a> {
+        CommoditiesHistoryBuilder::default()
+    }
+}
+
+impl<'a> CommoditiesHistoryBuilder<'a> {
+    /// Limit the number of results returned.
+    pub fn limit(&mut self, limit: u16) -> Result<&mut Self, ApiError<PaginationError>> {
+        let new = self;
+        new.limit = Some(Some(PageLimit::new(limit)?));
+        Ok(new)
+    }
+
+    /// Check that the date range is not reversed.
+    fn validate(&self) -> Result<(), String> {
+        common::validate_date_range(self.date_from.flatten(), self.date_to.flatten())
+    }
+}
+
+impl<'a> Endpoint for CommoditiesHistory<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "commoditieshistory".into()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("commodity_name", &self.commodity_name)
+            .push_opt("date_from", self.date_from)
+            .push_opt("date_to", self.date_to)
+            .push_opt("frequency", self.frequency)
+            .push_opt("sort", self.sort)
+            .push_opt("limit", self.limit.clone())
+            .push_opt("offset", self.offset);
+
+        params
+    }
+}
+
+impl<'a> Pageable for CommoditiesHistory<'a> {}
+
+impl<'a> TypedEndpoint for CommoditiesHistory<'a> {
+    type Response = CommoditiesHistoryData;
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    use crate::api::common::{Frequency, SortOrder};
+    use crate::api::v2::commodities::{Commodities, CommoditiesHistory};
+    use crate::api::{self, Query, TypedEndpoint};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+    use crate::ApiVersion;
+
+    #[test]
+    fn commodities_name_is_required() {
+        let err = Commodities::builder().build().unwrap_err();
+        assert_eq!(err.to_string(), "`commodity_name` must be initialized");
+    }
+
+    #[test]
+    fn commodities_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("commodities")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("commodity_name", "aluminum")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = Commodities::builder()
+            .commodity_name("aluminum")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn commodities_fetch() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("commodities")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("commodity_name", "aluminum")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "data": [{
+                    "commodity_name": "aluminum",
+                    "commodity_unit": "usd/t",
+                    "commodity_price": "2,612.00",
+                    "price_change_day": "-3.00",
+                    "percentage_day": "-0.11%",
+                    "percentage_week": "1.81%",
+                    "percentage_month": "4.06%",
+                    "percentage_year": "5.26%",
+                }],
+            }),
+        );
+
+        let endpoint = Commodities::builder()
+            .commodity_name("aluminum")
+            .build()
+            .unwrap();
+        let commodities = endpoint.fetch(&client).unwrap();
+        assert_eq!(commodities.data[0].commodity_price, 2612.0);
+        assert_eq!(commodities.data[0].commodity_unit.as_deref(), Some("usd/t"));
+    }
+
+    #[test]
+    fn commoditieshistory_name_is_required() {
+        let err = CommoditiesHistory::builder().build().unwrap_err();
+        assert_eq!(err.to_string(), "`commodity_name` must be initialized");
+    }
+
+    #[test]
+    fn commoditieshistory_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("commoditieshistory")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("commodity_name", "aluminum")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CommoditiesHistory::builder()
+            .commodity_name("aluminum")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn commoditieshistory_parameters() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("commoditieshistory")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[
+                ("commodity_name", "aluminum"),
+                ("date_from", "2024-01-01"),
+                ("date_to", "2024-12-31"),
+                ("frequency", "1m"),
+                ("sort", "ASC"),
+                ("limit", "12"),
+                ("offset", "0"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = CommoditiesHistory::builder()
+            .commodity_name("aluminum")
+            .date_from(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .date_to(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+            .frequency(Frequency::Monthly)
+            .sort(SortOrder::Ascending)
+            .limit(12)
+            .unwrap()
+            .offset(0)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn commoditieshistory_over_limit() {
+        assert!(CommoditiesHistory::builder().limit(9999).is_err());
+    }
+
+    #[test]
+    fn commoditieshistory_reversed_dates() {
+        let err = CommoditiesHistory::builder()
+            .commodity_name("aluminum")
+            .date_from(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+            .date_to(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "`date_from` must not be after `date_to`");
+    }
+
+    #[test]
+    fn commoditieshistory_fetch() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("commoditieshistory")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("commodity_name", "aluminum"), ("frequency", "1d")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "pagination": {"limit": 100, "offset": 0, "count": 2, "total": 2},
+                "data": [
+                    {
+                        "commodity_name": "aluminum",
+                        "commodity_unit": "usd/t",
+                        "commodity_price": "2,612.00",
+                        "date": "2024-12-31",
+                    },
+                    {
+                        "commodity_name": "aluminum",
+                        "commodity_unit": "usd/t",
+                        "commodity_price": 2590.5,
+                        "date": "2024-12-30",
+                    },
+                ],
+            }),
+        );
+
+        let endpoint = CommoditiesHistory::builder()
+            .commodity_name("aluminum")
+            .frequency(Frequency::Daily)
+            .build()
+            .unwrap();
+        let history = endpoint.fetch(&client).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history.data[0].commodity_price, 2612.0);
+        assert_eq!(
+            history.data[1].date,
+            NaiveDate::from_ymd_opt(2024, 12, 30).unwrap(),
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! Implemented endpoints for `eod`, `eod/latest` and `eod/[date]` of version 2.
+//!
+//! Wraps the [`Eod`](crate::api::eod::Eod) query of version 1, whose parameters are unchanged,
+//! but the rows carry the name, asset type, price currency and exchange code of the ticker.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use marketstack::api::eod;
+//! use marketstack::api::v2::eod::Eod;
+//! use marketstack::api::TypedEndpoint;
+//! use marketstack::Marketstack;
+//!
+//! let client = Marketstack::new("api.marketstack.com", "private-token").unwrap();
+//!
+//! let endpoint = Eod::builder()
+//!     .eod(eod::Eod::builder().symbol("AAPL").latest(true).build().unwrap())
+//!     .build()
+//!     .unwrap();
+//! let eod_data = endpoint.fetch(&client).unwrap();
+//!
+//! assert_eq!(eod_data.data[0].name.as_deref(), Some("Apple Inc"));
+//! ```
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::api::eod;
+use crate::api::paged::Pageable;
+use crate::types::v2::EodData;
+
+/// Query for `eod` of version 2.
+#[derive(Debug, Builder, Clone)]
+pub struct Eod<'a> {
+    /// The query of version 1 to send to version 2.
+    #[builder(setter(into))]
+    eod: eod::Eod<'a>,
+}
+
+impl<'a> Eod<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EodBuilder<'a> {
+        EodBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for Eod<'a> {
+    fn method(&self) -> Method {
+        self.eod.method()
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        self.eod.endpoint()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        self.eod.parameters()
+    }
+}
+
+impl<'a> Pageable for Eod<'a> {}
+
+impl<'a> TypedEndpoint for Eod<'a> {
+    type Response = EodData;
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    use crate::api::common::SortOrder;
+    use crate::api::eod;
+    use crate::api::v2::eod::Eod;
+    use crate::api::{self, Query, TypedEndpoint};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+    use crate::ApiVersion;
+
+    fn v2(eod: eod::Eod<'static>) -> Eod<'static> {
+        Eod::builder().eod(eod).build().unwrap()
+    }
+
+    #[test]
+    fn eod_eod_is_required() {
+        let err = Eod::builder().build().unwrap_err();
+        assert_eq!(err.to_string(), "`eod` must be initialized");
+    }
+
+    #[test]
+    fn eod_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("eod")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("symbols", "AAPL"), ("sort", "ASC")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = eod::Eod::builder()
+            .symbol("AAPL")
+            .sort(SortOrder::Ascending)
+            .build()
+            .unwrap();
+        api::ignore(v2(endpoint)).query(&client).unwrap();
+    }
+
+    #[test]
+    fn eod_latest() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("eod/latest")
+            .api_version(ApiVersion::V2)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = eod::Eod::builder().latest(true).build().unwrap();
+        api::ignore(v2(endpoint)).query(&client).unwrap();
+    }
+
+    #[test]
+    fn eod_date() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("eod/2024-09-27")
+            .api_version(ApiVersion::V2)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = eod::Eod::builder()
+            .date(NaiveDate::from_ymd_opt(2024, 9, 27).unwrap())
+            .build()
+            .unwrap();
+        api::ignore(v2(endpoint)).query(&client).unwrap();
+    }
+
+    #[test]
+    fn eod_fetch() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("eod")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("symbols", "AAPL")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "pagination": {"limit": 100, "offset": 0, "count": 1, "total": 1},
+                "data": [{
+                    "open": 228.46,
+                    "high": 229.52,
+                    "low": 227.3,
+                    "close": 227.79,
+                    "volume": 34025967.0,
+                    "adj_high": 229.52,
+                    "adj_low": 227.3,
+                    "adj_close": 227.79,
+                    "adj_open": 228.46,
+                    "adj_volume": 34025967.0,
+                    "split_factor": 1.0,
+                    "dividend": 0.0,
+                    "name": "Apple Inc",
+                    "exchange_code": "NASDAQ",
+                    "asset_type": "Stock",
+                    "price_currency": "usd",
+                    "symbol": "AAPL",
+                    "exchange": "XNAS",
+                    "date": "2024-09-27T00:00:00+0000",
+                }],
+            }),
+        );
+
+        let endpoint = v2(eod::Eod::builder().symbol("AAPL").build().unwrap());
+        let eod_data = endpoint.fetch(&client).unwrap();
+        assert_eq!(eod_data.data[0].eod.close, 227.79);
+        assert_eq!(eod_data.data[0].asset_type.as_deref(), Some("Stock"));
+        assert_eq!(eod_data.data[0].price_currency.as_deref(), Some("usd"));
+    }
+}
@@ -0,0 +1,341 @@
+//! Implemented endpoints for `etflist` and `etfholdings`.
+//!
+//! Both endpoints are part of version 2 of the Marketstack API and query it whichever
+//! [`ApiVersion`](crate::ApiVersion) the client was built for.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chrono::NaiveDate;
+//!
+//! use marketstack::api::v2::etf::EtfHoldings;
+//! use marketstack::api::Query;
+//! use marketstack::Marketstack;
+//! use marketstack::types::v2::EtfHoldingsData;
+//!
+//! let client = Marketstack::new("api.marketstack.com", "private-token").unwrap();
+//!
+//! // Create the `etfholdings` endpoint for the holdings reported in 2024.
+//! let endpoint = EtfHoldings::builder()
+//!     .ticker("VOO")
+//!     .date_from(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+//!     .date_to(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+//!     .build()
+//!     .unwrap();
+//! let holdings: EtfHoldingsData = endpoint.query(&client).unwrap();
+//!
+//! for report in &holdings {
+//!     for holding in &report.holdings {
+//!         println!("{:?}: {:?}%", holding.symbol, holding.weight);
+//!     }
+//! }
+//! ```
+
+use chrono::NaiveDate;
+use derive_builder::Builder;
+
+use crate::api::common;
+use crate::api::paged::{Pageable, PaginationError};
+use crate::api::{endpoint_prelude::*, ApiError};
+use crate::types::v2::{EtfHoldingsData, EtfListData};
+
+/// Query for `etflist`.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct EtfList<'a> {
+    /// Search ETFs by name or ticker symbol.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+    /// Pagination limit for API request.
+    #[builder(setter(name = "_limit"), default)]
+    limit: Option<PageLimit>,
+    /// Pagination offset value for API request.
+    #[builder(default)]
+    offset: Option<u64>,
+}
+
+impl<'a> EtfList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EtfListBuilder<'a> {
+        EtfListBuilder::default()
+    }
+}
+
+impl<'a> EtfListBuilder<'a> {
+    /// Limit the number of results returned.
+    pub fn limit(&mut self, limit: u16) -> Result<&mut Self, ApiError<PaginationError>> {
+        let new = self;
+        new.limit = Some(Some(PageLimit::new(limit)?));
+        Ok(new)
+    }
+}
+
+impl<'a> Endpoint for EtfList<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "etflist".into()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("search", self.search.as_ref())
+            .push_opt("limit", self.limit.clone())
+            .push_opt("offset", self.offset);
+
+        params
+    }
+}
+
+impl<'a> Pageable for EtfList<'a> {}
+
+impl<'a> TypedEndpoint for EtfList<'a> {
+    type Response = EtfListData;
+}
+
+/// Query for `etfholdings`.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct EtfHoldings<'a> {
+    /// Ticker symbol of the ETF.
+    #[builder(setter(into))]
+    ticker: Cow<'a, str>,
+    /// Date to query holdings from.
+    #[builder(default)]
+    date_from: Option<NaiveDate>,
+    /// Date to query holdings to.
+    #[builder(default)]
+    date_to: Option<NaiveDate>,
+    /// Pagination limit for API request.
+    #[builder(setter(name = "_limit"), default)]
+    limit: Option<PageLimit>,
+    /// Pagination offset value for API request.
+    #[builder(default)]
+    offset: Option<u64>,
+}
+
+impl<'a> EtfHoldings<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> EtfHoldingsBuilder<'a> {
+        EtfHoldingsBuilder::default()
+    }
+}
+
+impl<'a> EtfHoldingsBuilder<'a> {
+    /// Limit the number of results returned.
+    pub fn limit(&mut self, limit: u16) -> Result<&mut Self, ApiError<PaginationError>> {
+        let new = self;
+        new.limit = Some(Some(PageLimit::new(limit)?));
+        Ok(new)
+    }
+
+    /// Check that the date range is not reversed.
+    fn validate(&self) -> Result<(), String> {
+        common::validate_date_range(self.date_from.flatten(), self.date_to.flatten())
+    }
+}
+
+impl<'a> Endpoint for EtfHoldings<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "etfholdings".into()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("ticker", &self.ticker)
+            .push_opt("date_from", self.date_from)
+            .push_opt("date_to", self.date_to)
+            .push_opt("limit", self.limit.clone())
+            .push_opt("offset", self.offset);
+
+        params
+    }
+}
+
+impl<'a> Pageable for EtfHoldings<'a> {}
+
+impl<'a> TypedEndpoint for EtfHoldings<'a> {
+    type Response = EtfHoldingsData;
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    use crate::api::v2::etf::{EtfHoldings, EtfList};
+    use crate::api::{self, AsyncQuery, Query};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+    use crate::types::v2::{EtfHoldingsData, EtfListData};
+    use crate::ApiVersion;
+
+    #[test]
+    fn etflist_defaults_are_sufficient() {
+        EtfList::builder().build().unwrap();
+    }
+
+    #[test]
+    fn etflist_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("etflist")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("search", "Vanguard"), ("limit", "5"), ("offset", "10")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EtfList::builder()
+            .search("Vanguard")
+            .limit(5)
+            .unwrap()
+            .offset(10)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn etflist_over_limit() {
+        assert!(EtfList::builder().limit(9999).is_err());
+    }
+
+    #[test]
+    fn etflist_query() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("etflist")
+            .api_version(ApiVersion::V2)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "pagination": {"limit": 100, "offset": 0, "count": 1, "total": 1},
+                "data": [{"ticker": "VOO", "name": "Vanguard S&P 500 ETF"}],
+            }),
+        );
+
+        let endpoint = EtfList::builder().build().unwrap();
+        let etfs: EtfListData = endpoint.query(&client).unwrap();
+        assert_eq!(etfs.data[0].ticker, "VOO");
+    }
+
+    #[test]
+    fn etfholdings_ticker_is_required() {
+        let err = EtfHoldings::builder().build().unwrap_err();
+        assert_eq!(err.to_string(), "`ticker` must be initialized");
+    }
+
+    #[test]
+    fn etfholdings_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("etfholdings")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[
+                ("ticker", "VOO"),
+                ("date_from", "2024-01-01"),
+                ("date_to", "2024-12-31"),
+                ("limit", "1"),
+                ("offset", "2"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = EtfHoldings::builder()
+            .ticker("VOO")
+            .date_from(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .date_to(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+            .limit(1)
+            .unwrap()
+            .offset(2)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn etfholdings_reversed_dates() {
+        let err = EtfHoldings::builder()
+            .ticker("VOO")
+            .date_from(NaiveDate::from_ymd_opt(2024, 12, 31).unwrap())
+            .date_to(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())
+            .build()
+            .unwrap_err();
+
+        assert_eq!(err.to_string(), "`date_from` must not be after `date_to`");
+    }
+
+    #[tokio::test]
+    async fn etfholdings_query_async() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("etfholdings")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("ticker", "VOO")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "pagination": {"limit": 100, "offset": 0, "count": 1, "total": 1},
+                "data": [{
+                    "ticker": "VOO",
+                    "date": "2024-12-31",
+                    "holdings": [
+                        {
+                            "symbol": "AAPL",
+                            "name": "Apple Inc",
+                            "isin": "US0378331005",
+                            "weight": "7.62",
+                            "shares": "521,245,325",
+                            "market_value": 130532043210.0,
+                        },
+                        {
+                            "symbol": null,
+                            "name": "Cash",
+                            "weight": 0.01,
+                            "shares": 0,
+                            "market_value": "1,000",
+                        },
+                        {
+                            "symbol": "XYZ",
+                            "name": "Delisted Corp",
+                            "weight": null,
+                            "market_value": null,
+                        },
+                    ],
+                }],
+            }),
+        );
+
+        let endpoint = EtfHoldings::builder().ticker("VOO").build().unwrap();
+        let holdings: EtfHoldingsData = endpoint.query_async(&client).await.unwrap();
+        let report = &holdings.data[0];
+        assert_eq!(report.date, NaiveDate::from_ymd_opt(2024, 12, 31).unwrap());
+        assert_eq!(report.holdings[0].symbol.as_deref(), Some("AAPL"));
+        assert_eq!(report.holdings[0].weight, Some(7.62));
+        assert_eq!(report.holdings[0].shares, Some(521245325.0));
+        assert_eq!(report.holdings[1].symbol, None);
+        assert_eq!(report.holdings[1].market_value, Some(1000.0));
+        assert_eq!(report.holdings[2].weight, None);
+        assert_eq!(report.holdings[2].shares, None);
+        assert_eq!(report.holdings[2].market_value, None);
+    }
+}
@@ -0,0 +1,255 @@
+//! Implemented endpoints for `indexlist` and `indexinfo`.
+//!
+//! Both endpoints are part of version 2 of the Marketstack API and query it whichever
+//! [`ApiVersion`](crate::ApiVersion) the client was built for.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use marketstack::api::v2::indices::{IndexInfo, IndexList};
+//! use marketstack::api::TypedEndpoint;
+//! use marketstack::{EodRow, Marketstack};
+//!
+//! let client = Marketstack::new("api.marketstack.com", "private-token").unwrap();
+//!
+//! // List the available benchmarks.
+//! let endpoint = IndexList::builder().build().unwrap();
+//! let indices = endpoint.fetch(&client).unwrap();
+//!
+//! // Get the latest level of the first one.
+//! let endpoint = IndexInfo::builder()
+//!     .index(indices.data[0].benchmark.as_str())
+//!     .build()
+//!     .unwrap();
+//! let info = endpoint.fetch(&client).unwrap();
+//!
+//! // Index rows share `EodRow` with the rows of `eod`.
+//! println!("{} closed at {}", info.data[0].symbol(), info.data[0].close());
+//! ```
+
+use derive_builder::Builder;
+
+use crate::api::paged::{Pageable, PaginationError};
+use crate::api::{endpoint_prelude::*, ApiError};
+use crate::types::v2::{IndexInfoData, IndexListData};
+
+/// Query for `indexlist`.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct IndexList {
+    /// Pagination limit for API request.
+    #[builder(setter(name = "_limit"), default)]
+    limit: Option<PageLimit>,
+    /// Pagination offset value for API request.
+    #[builder(default)]
+    offset: Option<u64>,
+}
+
+impl IndexList {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IndexListBuilder {
+        IndexListBuilder::default()
+    }
+}
+
+impl IndexListBuilder {
+    /// Limit the number of results returned.
+    pub fn limit(&mut self, limit: u16) -> Result<&mut Self, ApiError<PaginationError>> {
+        let new = self;
+        new.limit = Some(Some(PageLimit::new(limit)?));
+        Ok(new)
+    }
+}
+
+impl Endpoint for IndexList {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "indexlist".into()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("limit", self.limit.clone())
+            .push_opt("offset", self.offset);
+
+        params
+    }
+}
+
+impl Pageable for IndexList {}
+
+impl TypedEndpoint for IndexList {
+    type Response = IndexListData;
+}
+
+/// Query for `indexinfo`.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct IndexInfo<'a> {
+    /// Identifier of the index, e.g. `us500`.
+    #[builder(setter(into))]
+    index: Cow<'a, str>,
+}
+
+impl<'a> IndexInfo<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> IndexInfoBuilder<'a> {
+        IndexInfoBuilder::default()
+    }
+}
+
+impl<'a> IndexInfoBuilder<'a> {
+    /// Check that an index is given.
+    fn validate(&self) -> Result<(), String> {
+        match &self.index {
+            Some(index) if index.trim().is_empty() => Err("`index` must not be empty".into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a> Endpoint for IndexInfo<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "indexinfo".into()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params.push("index", &self.index);
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for IndexInfo<'a> {
+    type Response = IndexInfoData;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::v2::indices::{IndexInfo, IndexList};
+    use crate::api::{self, Query, TypedEndpoint};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+    use crate::types::EodRow;
+    use crate::ApiVersion;
+
+    #[test]
+    fn indexlist_defaults_are_sufficient() {
+        IndexList::builder().build().unwrap();
+    }
+
+    #[test]
+    fn indexlist_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("indexlist")
+            .api_version(ApiVersion::V2)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IndexList::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn indexlist_limit_offset() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("indexlist")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("limit", "5"), ("offset", "10")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IndexList::builder()
+            .limit(5)
+            .unwrap()
+            .offset(10)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn indexlist_over_limit() {
+        assert!(IndexList::builder().limit(9999).is_err());
+    }
+
+    #[test]
+    fn indexinfo_index_is_required() {
+        let err = IndexInfo::builder().build().unwrap_err();
+        assert_eq!(err.to_string(), "`index` must be initialized");
+    }
+
+    #[test]
+    fn indexinfo_index_is_not_empty() {
+        let err = IndexInfo::builder().index("").build().unwrap_err();
+        assert_eq!(err.to_string(), "`index` must not be empty");
+
+        let err = IndexInfo::builder().index("  ").build().unwrap_err();
+        assert_eq!(err.to_string(), "`index` must not be empty");
+    }
+
+    #[test]
+    fn indexinfo_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("indexinfo")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("index", "us500")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = IndexInfo::builder().index("us500").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn indexinfo_fetch() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("indexinfo")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("index", "us500")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "data": [{
+                    "benchmark": "us500",
+                    "region": "united states",
+                    "country": "united states",
+                    "price": "5,638.94",
+                    "price_change_day": "117.42",
+                    "percentage_day": "2.13%",
+                    "date": "2025-03-14",
+                }],
+            }),
+        );
+
+        let endpoint = IndexInfo::builder().index("us500").build().unwrap();
+        let info = endpoint.fetch(&client).unwrap();
+        assert_eq!(info.data[0].symbol(), "us500");
+        assert_eq!(info.data[0].close(), 5638.94);
+    }
+}
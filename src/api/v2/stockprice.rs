@@ -0,0 +1,148 @@
+//! Implementation of the real-time `stockprice` API endpoint of version 2.
+//!
+//! Unlike `intraday/latest`, which returns the last intraday bar, this endpoint returns the price
+//! of the latest trade.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use marketstack::api::v2::stockprice::StockPrice;
+//! use marketstack::api::TypedEndpoint;
+//! use marketstack::Marketstack;
+//!
+//! let client = Marketstack::new("api.marketstack.com", "private-token").unwrap();
+//!
+//! let endpoint = StockPrice::builder().ticker("AAPL").build().unwrap();
+//! let stock_price = endpoint.fetch(&client).unwrap();
+//!
+//! assert!(stock_price.data.iter().all(|price| price.ticker == "AAPL"));
+//! ```
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::types::v2::StockPriceData;
+
+/// Query for `stockprice`.
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct StockPrice<'a> {
+    /// Ticker symbol to get the price of.
+    #[builder(setter(into))]
+    ticker: Cow<'a, str>,
+    /// Exchange to get the price on, as a MIC identification. Example: `XNAS`
+    #[builder(setter(into), default)]
+    exchange: Option<Cow<'a, str>>,
+}
+
+impl<'a> StockPrice<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> StockPriceBuilder<'a> {
+        StockPriceBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for StockPrice<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "stockprice".into()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push("ticker", &self.ticker)
+            .push_opt("exchange", self.exchange.as_ref());
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for StockPrice<'a> {
+    type Response = StockPriceData;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::v2::stockprice::StockPrice;
+    use crate::api::{self, Query, TypedEndpoint};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+    use crate::ApiVersion;
+
+    #[test]
+    fn stockprice_ticker_is_required() {
+        let err = StockPrice::builder().build().unwrap_err();
+        assert_eq!(err.to_string(), "`ticker` must be initialized");
+    }
+
+    #[test]
+    fn stockprice_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("stockprice")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("ticker", "AAPL")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StockPrice::builder().ticker("AAPL").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn stockprice_exchange() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("stockprice")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("ticker", "AAPL"), ("exchange", "XNAS")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = StockPrice::builder()
+            .ticker("AAPL")
+            .exchange("XNAS")
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn stockprice_fetch() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("stockprice")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("ticker", "AAPL")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "data": [{
+                    "exchange_code": "NASDAQ",
+                    "exchange_name": "NASDAQ - ALL MARKETS",
+                    "country": "USA",
+                    "ticker": "AAPL",
+                    "price": "213.49",
+                    "currency": "USD",
+                    "trade_last": "2025-03-14 16:00:00",
+                }],
+            }),
+        );
+
+        let endpoint = StockPrice::builder().ticker("AAPL").build().unwrap();
+        let stock_price = endpoint.fetch(&client).unwrap();
+        assert_eq!(stock_price.data[0].ticker, "AAPL");
+        assert_eq!(stock_price.data[0].price, 213.49);
+    }
+}
@@ -0,0 +1,122 @@
+//! Implementation of the `tickerinfo` API endpoint of version 2.
+//!
+//! This endpoint returns company details for a single ticker.
+
+use derive_builder::Builder;
+
+use crate::api::endpoint_prelude::*;
+use crate::types::v2::TickerInfoData;
+
+/// Query for `tickerinfo`.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct TickerInfo<'a> {
+    /// Ticker symbol to look up.
+    #[builder(setter(into))]
+    ticker: Cow<'a, str>,
+}
+
+impl<'a> TickerInfo<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TickerInfoBuilder<'a> {
+        TickerInfoBuilder::default()
+    }
+}
+
+impl<'a> Endpoint for TickerInfo<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "tickerinfo".into()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params.push("ticker", &self.ticker);
+
+        params
+    }
+}
+
+impl<'a> TypedEndpoint for TickerInfo<'a> {
+    type Response = TickerInfoData;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::v2::tickerinfo::TickerInfo;
+    use crate::api::{self, Query, TypedEndpoint};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+    use crate::ApiVersion;
+
+    #[test]
+    fn tickerinfo_ticker_is_required() {
+        let err = TickerInfo::builder().build().unwrap_err();
+        assert_eq!(err.to_string(), "`ticker` must be initialized");
+    }
+
+    #[test]
+    fn tickerinfo_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tickerinfo")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("ticker", "MSFT")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TickerInfo::builder().ticker("MSFT").build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn tickerinfo_fetch() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tickerinfo")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("ticker", "MSFT")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "data": {
+                    "name": "Microsoft Corporation",
+                    "ticker": "MSFT",
+                    "item_type": "equity",
+                    "sector": "Technology",
+                    "industry": "Software—Infrastructure",
+                    "exchange_code": "NASDAQ",
+                    "full_time_employees": "221000",
+                    "ipo_date": null,
+                    "key_executives": [{
+                        "name": "Mr. Satya  Nadella",
+                        "function": "Chairman & CEO",
+                        "salary": "$7.92M",
+                        "birth_year": "1967",
+                    }],
+                    "website": "https://www.microsoft.com",
+                },
+            }),
+        );
+
+        let endpoint = TickerInfo::builder().ticker("MSFT").build().unwrap();
+        let info = endpoint.fetch(&client).unwrap();
+        assert_eq!(info.data.ticker, "MSFT");
+        assert_eq!(info.data.sector.as_deref(), Some("Technology"));
+        assert_eq!(info.data.ipo_date, None);
+        assert_eq!(
+            info.data.key_executives[0].function.as_deref(),
+            Some("Chairman & CEO"),
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Implementation of the `tickerslist` API endpoint of version 2.
+//!
+//! This endpoint replaces the listing of `tickers` in version 1.
+
+use derive_builder::Builder;
+
+use crate::api::paged::{Pageable, PaginationError};
+use crate::api::{endpoint_prelude::*, ApiError};
+use crate::types::v2::TickersListData;
+
+/// Query for `tickerslist`.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option))]
+pub struct TickersList<'a> {
+    /// Search stock tickers by name or ticker symbol.
+    #[builder(setter(into), default)]
+    search: Option<Cow<'a, str>>,
+    /// MIC identification of a stock exchange to filter the results by. Example: `XNAS`
+    #[builder(setter(into), default)]
+    exchange: Option<Cow<'a, str>>,
+    /// Pagination limit for API request.
+    #[builder(setter(name = "_limit"), default)]
+    limit: Option<PageLimit>,
+    /// Pagination offset value for API request.
+    #[builder(default)]
+    offset: Option<u64>,
+}
+
+impl<'a> TickersList<'a> {
+    /// Create a builder for the endpoint.
+    pub fn builder() -> TickersListBuilder<'a> {
+        TickersListBuilder::default()
+    }
+}
+
+impl<'a> TickersListBuilder<'a> {
+    /// Limit the number of results returned.
+    pub fn limit(&mut self, limit: u16) -> Result<&mut Self, ApiError<PaginationError>> {
+        let new = self;
+        new.limit = Some(Some(PageLimit::new(limit)?));
+        Ok(new)
+    }
+}
+
+impl<'a> Endpoint for TickersList<'a> {
+    fn method(&self) -> Method {
+        Method::GET
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        "tickerslist".into()
+    }
+
+    fn api_version(&self) -> Option<ApiVersion> {
+        Some(ApiVersion::V2)
+    }
+
+    fn parameters(&self) -> QueryParams<'_> {
+        let mut params = QueryParams::default();
+
+        params
+            .push_opt("search", self.search.as_ref())
+            .push_opt("exchange", self.exchange.as_ref())
+            .push_opt("limit", self.limit.clone())
+            .push_opt("offset", self.offset);
+
+        params
+    }
+}
+
+impl<'a> Pageable for TickersList<'a> {}
+
+impl<'a> TypedEndpoint for TickersList<'a> {
+    type Response = TickersListData;
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::api::v2::tickerslist::TickersList;
+    use crate::api::{self, Query, TypedEndpoint};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+    use crate::ApiVersion;
+
+    #[test]
+    fn tickerslist_defaults_are_sufficient() {
+        TickersList::builder().build().unwrap();
+    }
+
+    #[test]
+    fn tickerslist_endpoint() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tickerslist")
+            .api_version(ApiVersion::V2)
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TickersList::builder().build().unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn tickerslist_search_exchange() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tickerslist")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[
+                ("search", "Apple"),
+                ("exchange", "XNAS"),
+                ("limit", "10"),
+                ("offset", "20"),
+            ])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_raw(endpoint, "");
+
+        let endpoint = TickersList::builder()
+            .search("Apple")
+            .exchange("XNAS")
+            .limit(10)
+            .unwrap()
+            .offset(20)
+            .build()
+            .unwrap();
+        api::ignore(endpoint).query(&client).unwrap();
+    }
+
+    #[test]
+    fn tickerslist_over_limit() {
+        assert!(TickersList::builder().limit(9999).is_err());
+    }
+
+    #[test]
+    fn tickerslist_fetch() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tickerslist")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("search", "AAPL")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "pagination": {"limit": 100, "offset": 0, "count": 1, "total": 1},
+                "data": [{
+                    "name": "Apple Inc",
+                    "ticker": "AAPL",
+                    "has_intraday": false,
+                    "has_eod": true,
+                    "stock_exchange": {
+                        "name": "NASDAQ - ALL MARKETS",
+                        "acronym": "NASDAQ",
+                        "mic": "XNAS",
+                    },
+                }],
+            }),
+        );
+
+        let endpoint = TickersList::builder().search("AAPL").build().unwrap();
+        let tickers = endpoint.fetch(&client).unwrap();
+        assert_eq!(tickers.data[0].ticker, "AAPL");
+        assert_eq!(tickers.data[0].stock_exchange.mic, "XNAS");
+    }
+}
@@ -1,8 +1,10 @@
+use std::error::Error;
+
 use thiserror::Error;
 
 use crate::api::{self, Query};
-use crate::api::{eod, AsyncQuery};
-use crate::types::EodData;
+use crate::api::{currencies, AsyncQuery};
+use crate::types::CurrenciesData;
 
 /// A Marketstack API token.
 ///
@@ -14,29 +16,34 @@ pub enum Auth {
 }
 
 impl Auth {
-    pub fn check_connection<C>(&self, api: &C) -> Result<(), api::ApiError<C::Error>>
+    /// The cheapest request which still requires a valid token.
+    fn probe() -> currencies::Currencies {
+        currencies::Currencies::builder()
+            .limit(1)
+            .unwrap()
+            .build()
+            .unwrap()
+    }
+
+    pub fn check_connection<C>(&self, api: &C) -> Result<(), ConnectionError<C::Error>>
     where
         C: api::Client,
     {
-        let _: EodData = eod::Eod::builder()
-            .symbol("AAPL")
-            .build()
-            .unwrap()
-            .query(api)?;
+        let _: CurrenciesData = Self::probe()
+            .query(api)
+            .map_err(ConnectionError::from_api)?;
 
         Ok(())
     }
 
-    pub async fn check_connection_async<C>(&self, api: &C) -> Result<(), api::ApiError<C::Error>>
+    pub async fn check_connection_async<C>(&self, api: &C) -> Result<(), ConnectionError<C::Error>>
     where
         C: api::AsyncClient + Sync,
     {
-        let _: EodData = eod::Eod::builder()
-            .symbol("AAPL")
-            .build()
-            .unwrap()
+        let _: CurrenciesData = Self::probe()
             .query_async(api)
-            .await?;
+            .await
+            .map_err(ConnectionError::from_api)?;
 
         Ok(())
     }
@@ -48,3 +55,195 @@ pub enum AuthError {
     #[error("missing auth error")]
     MissingAuth,
 }
+
+/// Reasons a connection to Marketstack could not be verified.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ConnectionError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// Marketstack did not accept the access key.
+    #[error("invalid access key: {}", message)]
+    InvalidKey {
+        /// The message from Marketstack.
+        message: String,
+    },
+    /// The subscription plan of the access key does not cover the request.
+    #[error("restricted by subscription plan: {}", message)]
+    PlanRestricted {
+        /// The message from Marketstack.
+        message: String,
+    },
+    /// Marketstack could not be reached.
+    #[error("could not reach marketstack: {}", source)]
+    Network {
+        /// The client error.
+        source: E,
+    },
+    /// Any other failure.
+    #[error("connection check failed: {}", source)]
+    Api {
+        /// The source of the error.
+        source: api::ApiError<E>,
+    },
+}
+
+impl<E> ConnectionError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// Classify the error of a failed probe request.
+    pub(crate) fn from_api(err: api::ApiError<E>) -> Self {
+        match err {
+            api::ApiError::Client { source } => ConnectionError::Network { source },
+            api::ApiError::MarketstackError { code, message, .. } if code.is_invalid_key() => {
+                ConnectionError::InvalidKey { message }
+            }
+            api::ApiError::MarketstackError { code, message, .. } if code.is_plan_restricted() => {
+                ConnectionError::PlanRestricted { message }
+            }
+            api::ApiError::MarketstackService { status, data } if status == 401 => {
+                ConnectionError::InvalidKey {
+                    message: String::from_utf8_lossy(&data).into_owned(),
+                }
+            }
+            source => ConnectionError::Api { source },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use http::StatusCode;
+    use serde_json::json;
+
+    use crate::api::{ApiError, ErrorCode};
+    use crate::auth::{Auth, ConnectionError};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+
+    fn probe_url(status: StatusCode) -> ExpectedUrl {
+        ExpectedUrl::builder()
+            .endpoint("currencies")
+            .add_query_params(&[("limit", "1"), ("access_key", "123")])
+            .status(status)
+            .build()
+            .unwrap()
+    }
+
+    fn error(code: &str) -> serde_json::Value {
+        json!({
+            "error": {
+                "code": code,
+                "message": "error message",
+            },
+        })
+    }
+
+    #[test]
+    fn check_connection() {
+        let client = SingleTestClient::new_json(
+            probe_url(StatusCode::OK),
+            &json!({
+                "pagination": {"limit": 1, "offset": 0, "count": 0, "total": 0},
+                "data": [],
+            }),
+        );
+
+        Auth::Token("123".into()).check_connection(&client).unwrap();
+    }
+
+    #[test]
+    fn check_connection_invalid_key() {
+        let client = SingleTestClient::new_json(
+            probe_url(StatusCode::UNAUTHORIZED),
+            &error("invalid_access_key"),
+        );
+
+        let err = Auth::Token("123".into())
+            .check_connection(&client)
+            .unwrap_err();
+        if let ConnectionError::InvalidKey { message } = err {
+            assert_eq!(message, "error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn check_connection_plan_restricted() {
+        let client = SingleTestClient::new_json(
+            probe_url(StatusCode::FORBIDDEN),
+            &error("https_access_restricted"),
+        );
+
+        let err = Auth::Token("123".into())
+            .check_connection(&client)
+            .unwrap_err();
+        if let ConnectionError::PlanRestricted { message } = err {
+            assert_eq!(message, "error message");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn check_connection_other_error() {
+        let client = SingleTestClient::new_json(
+            probe_url(StatusCode::TOO_MANY_REQUESTS),
+            &error("usage_limit_reached"),
+        );
+
+        let err = Auth::Token("123".into())
+            .check_connection(&client)
+            .unwrap_err();
+        if let ConnectionError::Api { source } = err {
+            assert_eq!(source.error_code(), Some(&ErrorCode::UsageLimitReached));
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn check_connection_async_invalid_key() {
+        let client = SingleTestClient::new_json(
+            probe_url(StatusCode::UNAUTHORIZED),
+            &error("missing_access_key"),
+        );
+
+        let err = Auth::Token("123".into())
+            .check_connection_async(&client)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ConnectionError::InvalidKey { .. }));
+    }
+
+    #[test]
+    fn network_error() {
+        let err = ApiError::client(io::Error::other("connection refused"));
+
+        let err = ConnectionError::from_api(err);
+        if let ConnectionError::Network { source } = err {
+            assert_eq!(source.to_string(), "connection refused");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[test]
+    fn unauthorized_without_json() {
+        let err = ApiError::<io::Error>::MarketstackService {
+            status: StatusCode::UNAUTHORIZED,
+            data: b"unauthorized".to_vec(),
+        };
+
+        let err = ConnectionError::from_api(err);
+        if let ConnectionError::InvalidKey { message } = err {
+            assert_eq!(message, "unauthorized");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+}
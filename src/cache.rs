@@ -0,0 +1,822 @@
+//! Caching of responses.
+//!
+//! Historical data from Marketstack does not change, so querying it repeatedly only wastes the
+//! monthly quota. [`CachedClient`] wraps any [`Client`] or [`AsyncClient`] and keeps successful
+//! `GET` responses in a [`CacheStore`], keyed by their URL without the `access_key`.
+//!
+//! How long a response is kept is decided by [`CacheConfig`]:
+//!
+//! * `eod/[date]` and `intraday/[date]` for dates in the past are kept forever;
+//! * `eod/latest` and `intraday/latest` are only kept briefly;
+//! * everything else uses a default which may be overridden per endpoint.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use marketstack::api::{self, Query};
+//! use marketstack::api::eod::Eod;
+//! use marketstack::{CacheConfig, CachedClient, DiskStore, EodData, Marketstack, Ttl};
+//!
+//! let client = Marketstack::new("api.marketstack.com", "private-token").unwrap();
+//!
+//! let config = CacheConfig::builder()
+//!     .endpoint_ttl("tickers", Ttl::For(Duration::from_secs(24 * 60 * 60)))
+//!     .build()
+//!     .unwrap();
+//! let client = CachedClient::with_config(client, DiskStore::new("marketstack-cache"), config);
+//!
+//! let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+//! let eod_data: EodData = endpoint.query(&client).unwrap();
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, NaiveDate, Utc};
+use derive_builder::Builder;
+use http::request::Builder as RequestBuilder;
+use http::{Method, Response, StatusCode};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::api::{ApiError, AsyncClient, Client, RestClient};
+use crate::auth::Auth;
+use crate::ApiVersion;
+
+/// How long a response may be served from the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ttl {
+    /// Keep the response forever.
+    Forever,
+    /// Keep the response for the given duration.
+    For(Duration),
+    /// Do not cache the response.
+    Never,
+}
+
+/// A cached response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CacheEntry {
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// When the entry stops being valid, or `None` if it never does.
+    pub expires: Option<DateTime<Utc>>,
+    /// The headers of the response, in order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<(String, String)>,
+    /// The body of the response.
+    pub body: String,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        self.expires.is_none_or(|expires| now < expires)
+    }
+
+    fn response(&self) -> Option<Response<Bytes>> {
+        let status = StatusCode::from_u16(self.status).ok()?;
+        let mut rsp = Response::builder().status(status);
+        for (name, value) in &self.headers {
+            rsp = rsp.header(name, value);
+        }
+        rsp.body(Bytes::from(self.body.clone())).ok()
+    }
+}
+
+/// Storage for cached responses.
+pub trait CacheStore: Send + Sync {
+    /// Look up the entry for a key.
+    fn get(&self, key: &str) -> Option<CacheEntry>;
+    /// Store the entry for a key.
+    fn put(&self, key: &str, entry: CacheEntry);
+}
+
+/// A cache store which keeps entries in memory.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl MemoryStore {
+    /// Create an empty in-memory store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of entries held.
+    pub fn len(&self) -> usize {
+        self.entries.lock().expect("cache lock poisoned").len()
+    }
+
+    /// Whether the store holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl CacheStore for MemoryStore {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        self.entries
+            .lock()
+            .expect("cache lock poisoned")
+            .get(key)
+            .cloned()
+    }
+
+    fn put(&self, key: &str, entry: CacheEntry) {
+        self.entries
+            .lock()
+            .expect("cache lock poisoned")
+            .insert(key.into(), entry);
+    }
+}
+
+/// A cache store which keeps one JSON file per entry in a directory.
+#[derive(Debug, Clone)]
+pub struct DiskStore {
+    dir: PathBuf,
+}
+
+/// The on-disk form of an entry, which keeps the key to guard against hash collisions.
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    key: String,
+    #[serde(flatten)]
+    entry: CacheEntry,
+}
+
+impl DiskStore {
+    /// Create a store in the given directory, which is created when first written to.
+    pub fn new<P>(dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { dir: dir.into() }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.dir
+            .join(format!("{:016x}.json", fnv1a(key.as_bytes())))
+    }
+}
+
+/// The 64-bit FNV-1a hash of some data.
+///
+/// Unlike `DefaultHasher`, this is stable across Rust releases, so entries written by one build
+/// are found by the next.
+fn fnv1a(data: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    data.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+impl CacheStore for DiskStore {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        let data = fs::read(self.path(key)).ok()?;
+        let stored: DiskEntry = serde_json::from_slice(&data).ok()?;
+        if stored.key == key {
+            Some(stored.entry)
+        } else {
+            None
+        }
+    }
+
+    fn put(&self, key: &str, entry: CacheEntry) {
+        let stored = DiskEntry {
+            key: key.into(),
+            entry,
+        };
+        let write = || -> std::io::Result<()> {
+            fs::create_dir_all(&self.dir)?;
+            fs::write(self.path(key), serde_json::to_vec(&stored)?)
+        };
+        if let Err(err) = write() {
+            warn!(target: "marketstack", "failed to write cache entry for {}: {}", key, err);
+        }
+    }
+}
+
+/// How long responses from each endpoint are cached.
+#[derive(Debug, Builder, Clone)]
+pub struct CacheConfig {
+    /// TTL for endpoints without a more specific rule.
+    #[builder(default = "Ttl::For(Duration::from_secs(60 * 60))")]
+    default_ttl: Ttl,
+    /// TTL for `eod/latest` and `intraday/latest`, including nested forms.
+    #[builder(default = "Ttl::For(Duration::from_secs(60))")]
+    latest_ttl: Ttl,
+    /// TTL for `eod/[date]` and `intraday/[date]` with a date before today.
+    #[builder(default = "Ttl::Forever")]
+    historical_ttl: Ttl,
+    /// TTLs for specific endpoints, matched by path prefix, e.g. `tickers`.
+    #[builder(setter(custom), default)]
+    endpoint_ttls: Vec<(String, Ttl)>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        CacheConfigBuilder::default().build().unwrap()
+    }
+}
+
+impl CacheConfigBuilder {
+    /// Use a TTL for `endpoint` and every endpoint nested below it.
+    ///
+    /// The longest matching prefix wins over every other rule.
+    pub fn endpoint_ttl<E>(&mut self, endpoint: E, ttl: Ttl) -> &mut Self
+    where
+        E: Into<String>,
+    {
+        self.endpoint_ttls
+            .get_or_insert_with(Vec::new)
+            .push((endpoint.into(), ttl));
+        self
+    }
+}
+
+impl CacheConfig {
+    /// Create a builder for the configuration.
+    pub fn builder() -> CacheConfigBuilder {
+        CacheConfigBuilder::default()
+    }
+
+    /// The TTL for an endpoint path such as `eod/2023-01-04`.
+    pub fn ttl(&self, endpoint: &str, today: NaiveDate) -> Ttl {
+        let endpoint = endpoint.trim_matches('/');
+        let rule = self
+            .endpoint_ttls
+            .iter()
+            .filter(|(prefix, _)| {
+                let prefix = prefix.trim_matches('/');
+                endpoint == prefix || endpoint.starts_with(&format!("{}/", prefix))
+            })
+            .max_by_key(|(prefix, _)| prefix.len());
+        if let Some((_, ttl)) = rule {
+            return *ttl;
+        }
+
+        let mut segments = endpoint.rsplit('/');
+        let last = segments.next().unwrap_or_default();
+        let feature = segments.next().unwrap_or_default();
+        if feature != "eod" && feature != "intraday" {
+            return self.default_ttl;
+        }
+
+        if last == "latest" {
+            self.latest_ttl
+        } else if NaiveDate::parse_from_str(last, "%Y-%m-%d").is_ok_and(|date| date < today) {
+            self.historical_ttl
+        } else {
+            self.default_ttl
+        }
+    }
+}
+
+/// A client wrapper which serves repeated requests from a [`CacheStore`].
+#[derive(Clone)]
+pub struct CachedClient<C> {
+    client: C,
+    store: Arc<dyn CacheStore>,
+    config: CacheConfig,
+}
+
+impl<C> Debug for CachedClient<C>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedClient")
+            .field("client", &self.client)
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl<C> CachedClient<C>
+where
+    C: RestClient,
+{
+    /// Wrap a client with a cache using the default configuration.
+    pub fn new<S>(client: C, store: S) -> Self
+    where
+        S: CacheStore + 'static,
+    {
+        Self::with_config(client, store, CacheConfig::default())
+    }
+
+    /// Wrap a client with a cache.
+    pub fn with_config<S>(client: C, store: S, config: CacheConfig) -> Self
+    where
+        S: CacheStore + 'static,
+    {
+        Self {
+            client,
+            store: Arc::new(store),
+            config,
+        }
+    }
+
+    /// The wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.client
+    }
+
+    /// The cache key and TTL for a request, if it may be cached at all.
+    fn lookup(&self, request: &RequestBuilder) -> Option<(String, Ttl)> {
+        if request.method_ref() != Some(&Method::GET) {
+            return None;
+        }
+        let url = Url::parse(&request.uri_ref()?.to_string()).ok()?;
+        let base = self.client.rest_endpoint("").ok()?;
+        let endpoint = url.path().strip_prefix(base.path()).unwrap_or(url.path());
+
+        let ttl = self.config.ttl(endpoint, Utc::now().date_naive());
+        if ttl == Ttl::Never {
+            return None;
+        }
+
+        Some((cache_key(&url), ttl))
+    }
+
+    fn cached(&self, key: &str) -> Option<Response<Bytes>> {
+        self.store
+            .get(key)
+            .filter(|entry| entry.is_fresh(Utc::now()))
+            .and_then(|entry| entry.response())
+    }
+
+    fn store(&self, key: &str, ttl: Ttl, rsp: &Response<Bytes>) {
+        if !rsp.status().is_success() {
+            return;
+        }
+        let body = if let Ok(body) = std::str::from_utf8(rsp.body()) {
+            body.into()
+        } else {
+            return;
+        };
+        let expires = match ttl {
+            Ttl::For(ttl) => chrono::Duration::from_std(ttl)
+                .ok()
+                .and_then(|ttl| Utc::now().checked_add_signed(ttl)),
+            Ttl::Forever | Ttl::Never => None,
+        };
+        // Headers which are not valid UTF-8 cannot be stored as JSON strings.
+        let headers = rsp
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.as_str().into(), value.to_str().ok()?.into())))
+            .collect();
+
+        self.store.put(
+            key,
+            CacheEntry {
+                status: rsp.status().as_u16(),
+                expires,
+                headers,
+                body,
+            },
+        );
+    }
+}
+
+/// The URL of a request with the `access_key` removed.
+fn cache_key(url: &Url) -> String {
+    let mut key = url.clone();
+    let pairs: Vec<(String, String)> = url
+        .query_pairs()
+        .filter(|(name, _)| name != "access_key")
+        .map(|(name, value)| (name.into_owned(), value.into_owned()))
+        .collect();
+    if pairs.is_empty() {
+        key.set_query(None);
+    } else {
+        key.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+    key.into()
+}
+
+impl<C> RestClient for CachedClient<C>
+where
+    C: RestClient,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.rest_endpoint(endpoint)
+    }
+
+    fn versioned_rest_endpoint(
+        &self,
+        version: ApiVersion,
+        endpoint: &str,
+    ) -> Result<Url, ApiError<Self::Error>> {
+        self.client.versioned_rest_endpoint(version, endpoint)
+    }
+
+    fn get_auth(&self) -> Option<Auth> {
+        self.client.get_auth()
+    }
+}
+
+impl<C> Client for CachedClient<C>
+where
+    C: Client,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let lookup = self.lookup(&request);
+        if let Some((key, ttl)) = lookup {
+            if let Some(rsp) = self.cached(&key) {
+                return Ok(rsp);
+            }
+            let rsp = self.client.rest(request, body)?;
+            self.store(&key, ttl, &rsp);
+            Ok(rsp)
+        } else {
+            self.client.rest(request, body)
+        }
+    }
+}
+
+#[async_trait]
+impl<C> AsyncClient for CachedClient<C>
+where
+    C: AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let lookup = self.lookup(&request);
+        if let Some((key, ttl)) = lookup {
+            if let Some(rsp) = self.cached(&key) {
+                return Ok(rsp);
+            }
+            let rsp = self.client.rest_async(request, body).await?;
+            self.store(&key, ttl, &rsp);
+            Ok(rsp)
+        } else {
+            self.client.rest_async(request, body).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use bytes::Bytes;
+    use chrono::{NaiveDate, Utc};
+    use http::request::Builder as RequestBuilder;
+    use http::{Response, StatusCode};
+    use serde_json::json;
+    use url::Url;
+
+    use crate::api::eod::Eod;
+    use crate::api::{self, ApiError, AsyncClient, AsyncQuery, Client, Query, RestClient};
+    use crate::auth::Auth;
+    use crate::cache::{
+        fnv1a, CacheConfig, CacheEntry, CacheStore, CachedClient, DiskStore, MemoryStore, Ttl,
+    };
+    use crate::testing::{ExpectedUrl, SingleTestClient, TestClientError};
+    use crate::ApiVersion;
+
+    /// Count the requests which reach the wrapped client.
+    struct Counting {
+        client: SingleTestClient,
+        requests: AtomicUsize,
+    }
+
+    impl Counting {
+        fn new(client: SingleTestClient) -> Self {
+            Self {
+                client,
+                requests: AtomicUsize::new(0),
+            }
+        }
+
+        fn requests(&self) -> usize {
+            self.requests.load(Ordering::SeqCst)
+        }
+    }
+
+    impl RestClient for Counting {
+        type Error = TestClientError;
+
+        fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+            self.client.rest_endpoint(endpoint)
+        }
+
+        fn versioned_rest_endpoint(
+            &self,
+            version: ApiVersion,
+            endpoint: &str,
+        ) -> Result<Url, ApiError<Self::Error>> {
+            self.client.versioned_rest_endpoint(version, endpoint)
+        }
+
+        fn get_auth(&self) -> Option<Auth> {
+            self.client.get_auth()
+        }
+    }
+
+    impl Client for Counting {
+        fn rest(
+            &self,
+            request: RequestBuilder,
+            body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            self.client.rest(request, body)
+        }
+    }
+
+    #[async_trait]
+    impl AsyncClient for Counting {
+        async fn rest_async(
+            &self,
+            request: RequestBuilder,
+            body: Vec<u8>,
+        ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+            self.rest(request, body)
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn eod_client(endpoint: &'static str, status: StatusCode) -> Counting {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint(endpoint)
+            .add_query_params(&[("access_key", "123"), ("symbols", "AAPL")])
+            .status(status)
+            .build()
+            .unwrap();
+        Counting::new(SingleTestClient::new_json(
+            endpoint,
+            &json!({
+                "value": 1,
+            }),
+        ))
+    }
+
+    #[test]
+    fn cache_config_defaults_are_sufficient() {
+        CacheConfig::builder().build().unwrap();
+    }
+
+    #[test]
+    fn cache_config_ttls() {
+        let config = CacheConfig::default();
+        let today = date(2023, 10, 5);
+
+        assert_eq!(config.ttl("eod/2023-10-04", today), Ttl::Forever);
+        assert_eq!(
+            config.ttl("tickers/AAPL/eod/2023-01-04", today),
+            Ttl::Forever
+        );
+        assert_eq!(config.ttl("intraday/2023-10-04", today), Ttl::Forever);
+        assert_eq!(
+            config.ttl("eod/2023-10-05", today),
+            Ttl::For(Duration::from_secs(60 * 60)),
+        );
+        assert_eq!(
+            config.ttl("eod/latest", today),
+            Ttl::For(Duration::from_secs(60)),
+        );
+        assert_eq!(
+            config.ttl("exchanges/XNAS/intraday/latest", today),
+            Ttl::For(Duration::from_secs(60)),
+        );
+        assert_eq!(
+            config.ttl("eod", today),
+            Ttl::For(Duration::from_secs(60 * 60)),
+        );
+    }
+
+    #[test]
+    fn cache_config_endpoint_ttls() {
+        let config = CacheConfig::builder()
+            .endpoint_ttl("tickers", Ttl::Never)
+            .endpoint_ttl("tickers/AAPL", Ttl::Forever)
+            .build()
+            .unwrap();
+        let today = date(2023, 10, 5);
+
+        assert_eq!(config.ttl("tickers", today), Ttl::Never);
+        assert_eq!(config.ttl("tickers/MSFT/eod/2023-01-04", today), Ttl::Never);
+        assert_eq!(config.ttl("tickers/AAPL/eod/latest", today), Ttl::Forever);
+    }
+
+    #[test]
+    fn cached_client_serves_repeats() {
+        let store = MemoryStore::new();
+        let client = CachedClient::new(eod_client("eod/2023-01-04", StatusCode::OK), store);
+
+        let endpoint = Eod::builder()
+            .symbol("AAPL")
+            .date(date(2023, 1, 4))
+            .build()
+            .unwrap();
+        for _ in 0..3 {
+            let value: serde_json::Value = endpoint.query(&client).unwrap();
+            assert_eq!(value["value"], 1);
+        }
+        assert_eq!(client.inner().requests(), 1);
+    }
+
+    #[test]
+    fn cached_client_key_strips_access_key() {
+        let store = std::sync::Arc::new(MemoryStore::new());
+
+        struct Shared(std::sync::Arc<MemoryStore>);
+
+        impl CacheStore for Shared {
+            fn get(&self, key: &str) -> Option<CacheEntry> {
+                self.0.get(key)
+            }
+
+            fn put(&self, key: &str, entry: CacheEntry) {
+                self.0.put(key, entry)
+            }
+        }
+
+        let client = CachedClient::new(
+            eod_client("eod/2023-01-04", StatusCode::OK),
+            Shared(store.clone()),
+        );
+        let endpoint = Eod::builder()
+            .symbol("AAPL")
+            .date(date(2023, 1, 4))
+            .build()
+            .unwrap();
+        let _: serde_json::Value = endpoint.query(&client).unwrap();
+
+        let entry = store
+            .get("https://marketstack.host.invalid/v1/eod/2023-01-04?symbols=AAPL")
+            .unwrap();
+        assert_eq!(entry.status, 200);
+        assert_eq!(entry.expires, None);
+    }
+
+    #[test]
+    fn cached_client_skips_errors() {
+        let store = MemoryStore::new();
+        let client = CachedClient::new(
+            eod_client("eod/2023-01-04", StatusCode::TOO_MANY_REQUESTS),
+            store,
+        );
+
+        let endpoint = Eod::builder()
+            .symbol("AAPL")
+            .date(date(2023, 1, 4))
+            .build()
+            .unwrap();
+        for _ in 0..2 {
+            let res: Result<serde_json::Value, _> = endpoint.query(&client);
+            assert!(res.is_err());
+        }
+        assert_eq!(client.inner().requests(), 2);
+    }
+
+    #[test]
+    fn cached_client_never() {
+        let config = CacheConfig::builder()
+            .default_ttl(Ttl::Never)
+            .build()
+            .unwrap();
+        let client = CachedClient::with_config(
+            eod_client("eod", StatusCode::OK),
+            MemoryStore::new(),
+            config,
+        );
+
+        let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+        for _ in 0..2 {
+            let _: serde_json::Value = endpoint.query(&client).unwrap();
+        }
+        assert_eq!(client.inner().requests(), 2);
+    }
+
+    #[test]
+    fn cached_client_expired_entry() {
+        let store = MemoryStore::new();
+        store.put(
+            "https://marketstack.host.invalid/v1/eod/latest?symbols=AAPL",
+            CacheEntry {
+                status: 200,
+                expires: Some(Utc::now() - chrono::Duration::seconds(1)),
+                headers: Vec::new(),
+                body: r#"{"value": 2}"#.into(),
+            },
+        );
+        let client = CachedClient::new(eod_client("eod/latest", StatusCode::OK), store);
+
+        let endpoint = Eod::builder().symbol("AAPL").latest(true).build().unwrap();
+        let value: serde_json::Value = endpoint.query(&client).unwrap();
+        assert_eq!(value["value"], 1);
+        assert_eq!(client.inner().requests(), 1);
+    }
+
+    #[test]
+    fn cached_client_keeps_headers() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("eod/2023-01-04")
+            .add_query_params(&[("symbols", "AAPL")])
+            .response_headers(vec![("x-request-id", "abc123")])
+            .build()
+            .unwrap();
+        let client = CachedClient::new(
+            Counting::new(SingleTestClient::new_json(endpoint, &json!({"value": 1}))),
+            MemoryStore::new(),
+        );
+
+        let endpoint = Eod::builder()
+            .symbol("AAPL")
+            .date(date(2023, 1, 4))
+            .build()
+            .unwrap();
+        let miss: api::Response<serde_json::Value> =
+            api::with_meta(endpoint.clone()).query(&client).unwrap();
+        let hit: api::Response<serde_json::Value> =
+            api::with_meta(endpoint).query(&client).unwrap();
+
+        assert_eq!(client.inner().requests(), 1);
+        assert_eq!(miss.header("x-request-id"), Some("abc123"));
+        assert_eq!(hit.header("x-request-id"), Some("abc123"));
+        assert_eq!(hit.headers, miss.headers);
+        assert_eq!(hit.data, miss.data);
+    }
+
+    #[tokio::test]
+    async fn cached_client_serves_repeats_async() {
+        let client =
+            CachedClient::new(eod_client("eod/latest", StatusCode::OK), MemoryStore::new());
+
+        let endpoint = Eod::builder().symbol("AAPL").latest(true).build().unwrap();
+        for _ in 0..2 {
+            let value: serde_json::Value = endpoint.query_async(&client).await.unwrap();
+            assert_eq!(value["value"], 1);
+        }
+        assert_eq!(client.inner().requests(), 1);
+    }
+
+    #[test]
+    fn disk_store_path_is_stable() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+
+        let store = DiskStore::new("cache");
+        assert_eq!(
+            store.path("eod?symbols=AAPL"),
+            std::path::Path::new("cache").join(format!("{:016x}.json", fnv1a(b"eod?symbols=AAPL"))),
+        );
+    }
+
+    #[test]
+    fn disk_store_roundtrip() {
+        let dir = std::env::temp_dir().join(format!(
+            "marketstack-cache-test-{}-{}",
+            std::process::id(),
+            Utc::now().timestamp_nanos_opt().unwrap_or_default(),
+        ));
+        let store = DiskStore::new(&dir);
+
+        assert!(store.get("key").is_none());
+        store.put(
+            "key",
+            CacheEntry {
+                status: 200,
+                expires: None,
+                headers: vec![("x-request-id".into(), "abc123".into())],
+                body: "{}".into(),
+            },
+        );
+        let entry = store.get("key").unwrap();
+        assert_eq!(entry.status, 200);
+        assert_eq!(entry.headers, [("x-request-id".into(), "abc123".into())]);
+        assert_eq!(entry.body, "{}");
+        assert!(store.get("other").is_none());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
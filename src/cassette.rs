@@ -0,0 +1,674 @@
+//! Recording and replaying of API interactions.
+//!
+//! A [`Cassette`] wraps a client and either records every request made through it, along with
+//! the response, to a JSON file, or replays responses from such a file without touching the
+//! network. The `access_key` is redacted from every recorded URL, so recordings may be committed
+//! as test fixtures.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use marketstack::api::Query;
+//! use marketstack::api::eod::Eod;
+//! use marketstack::{Cassette, EodData, Marketstack};
+//!
+//! let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+//!
+//! // Record the interaction against the live API once...
+//! let client = Marketstack::new("api.marketstack.com", "private-token").unwrap();
+//! let recorder = Cassette::record(client, "tests/fixtures/eod.json");
+//! let _: EodData = endpoint.query(&recorder).unwrap();
+//!
+//! // ...and replay it offline afterwards.
+//! let replay: Cassette<Marketstack> = Cassette::replay("tests/fixtures/eod.json").unwrap();
+//! let eod_data: EodData = endpoint.query(&replay).unwrap();
+//! ```
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Debug};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::{Response, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use url::Url;
+
+use crate::api::{self, ApiError, AsyncClient, Client, RestClient, REDACTED};
+use crate::auth::Auth;
+use crate::ApiVersion;
+
+/// Errors which may occur when recording or replaying interactions.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum CassetteError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// The wrapped client encountered an error while recording.
+    #[error("client error: {}", source)]
+    Client {
+        /// The client error.
+        source: E,
+    },
+    /// The cassette holds no response for a request.
+    #[error("no recorded interaction for {} {}", method, url)]
+    NoInteraction {
+        /// The method of the request.
+        method: String,
+        /// The URL of the request, with the `access_key` redacted.
+        url: String,
+    },
+    /// The cassette holds no interactions with a version of the API.
+    #[error("no recorded interaction with version {} of the API", version.as_str())]
+    NoVersion {
+        /// The version of the API.
+        version: ApiVersion,
+    },
+    /// The cassette file could not be read or written.
+    #[error("failed to access cassette {}: {}", path.display(), source)]
+    Io {
+        /// The path of the cassette.
+        path: PathBuf,
+        /// The source of the error.
+        source: std::io::Error,
+    },
+    /// The cassette file is not valid.
+    #[error("failed to parse cassette {}: {}", path.display(), source)]
+    Json {
+        /// The path of the cassette.
+        path: PathBuf,
+        /// The source of the error.
+        source: serde_json::Error,
+    },
+    /// The recorded response is not valid.
+    #[error("invalid recorded response: {}", source)]
+    Http {
+        /// The source of the error.
+        #[from]
+        source: http::Error,
+    },
+}
+
+/// A recorded request and its response.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Interaction {
+    /// The method of the request.
+    pub method: String,
+    /// The URL of the request, with the `access_key` redacted.
+    pub url: String,
+    /// The HTTP status code of the response.
+    pub status: u16,
+    /// The body of the response.
+    ///
+    /// JSON bodies are stored as-is; anything else is stored as a string with `raw` set.
+    pub body: Value,
+    /// Whether `body` holds the response verbatim rather than parsed JSON.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub raw: bool,
+}
+
+impl Interaction {
+    fn response(&self) -> Result<Response<Bytes>, http::Error> {
+        let body = match (&self.body, self.raw) {
+            (Value::String(body), true) => Bytes::from(body.clone()),
+            (body, _) => Bytes::from(body.to_string()),
+        };
+        Response::builder()
+            .status(StatusCode::from_u16(self.status)?)
+            .body(body)
+    }
+}
+
+/// The on-disk form of a cassette.
+#[derive(Serialize, Deserialize, Default)]
+struct Tape {
+    /// The base URL of the recorded API.
+    base_url: Option<String>,
+    /// The base URLs of the versions of the API requested by endpoints, by version.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    version_urls: BTreeMap<String, String>,
+    /// The recorded interactions, in order.
+    interactions: Vec<Interaction>,
+}
+
+enum Mode<C> {
+    Record(C),
+    Replay {
+        base_url: Url,
+        played: Mutex<Vec<bool>>,
+    },
+}
+
+/// A client wrapper which records interactions to, or replays them from, a JSON file.
+pub struct Cassette<C> {
+    mode: Mode<C>,
+    path: PathBuf,
+    version_urls: Mutex<BTreeMap<String, String>>,
+    interactions: Mutex<Vec<Interaction>>,
+}
+
+impl<C> Debug for Cassette<C>
+where
+    C: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Cassette");
+        match &self.mode {
+            Mode::Record(client) => debug.field("record", client),
+            Mode::Replay { base_url, .. } => debug.field("replay", base_url),
+        };
+        debug.field("path", &self.path).finish()
+    }
+}
+
+impl<C> Cassette<C>
+where
+    C: RestClient,
+{
+    /// Record every interaction made through `client` to `path`.
+    ///
+    /// Any existing recording at `path` is replaced once the first request is made.
+    pub fn record<P>(client: C, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            mode: Mode::Record(client),
+            path: path.into(),
+            version_urls: Mutex::new(BTreeMap::new()),
+            interactions: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Replay the interactions recorded at `path`.
+    ///
+    /// Requests are answered by the first recorded interaction with the same method and URL
+    /// which has not been played yet, or by the last matching one once all have been played.
+    pub fn replay<P>(path: P) -> Result<Self, CassetteError<C::Error>>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref().to_path_buf();
+        let data = fs::read(&path).map_err(|source| CassetteError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        let tape: Tape = serde_json::from_slice(&data).map_err(|source| CassetteError::Json {
+            path: path.clone(),
+            source,
+        })?;
+        let base_url = tape
+            .base_url
+            .as_deref()
+            .unwrap_or("https://api.marketstack.com/v1/");
+        let base_url = Url::parse(base_url).map_err(|err| CassetteError::Json {
+            path: path.clone(),
+            source: serde::de::Error::custom(err),
+        })?;
+
+        Ok(Self {
+            mode: Mode::Replay {
+                base_url,
+                played: Mutex::new(vec![false; tape.interactions.len()]),
+            },
+            path,
+            version_urls: Mutex::new(tape.version_urls),
+            interactions: Mutex::new(tape.interactions),
+        })
+    }
+
+    /// The wrapped client, if recording.
+    pub fn inner(&self) -> Option<&C> {
+        match &self.mode {
+            Mode::Record(client) => Some(client),
+            Mode::Replay { .. } => None,
+        }
+    }
+
+    /// The interactions recorded so far, or available for replay.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.interactions
+            .lock()
+            .expect("cassette lock poisoned")
+            .clone()
+    }
+
+    /// The method and redacted URL of a request.
+    fn key(request: &RequestBuilder) -> (String, String) {
+        let method = request
+            .method_ref()
+            .map(|method| method.as_str().into())
+            .unwrap_or_default();
+        let url = request
+            .uri_ref()
+            .map(|uri| redact(&uri.to_string()))
+            .unwrap_or_default();
+        (method, url)
+    }
+
+    fn play(
+        &self,
+        played: &Mutex<Vec<bool>>,
+        method: String,
+        url: String,
+    ) -> Result<Response<Bytes>, ApiError<CassetteError<C::Error>>> {
+        let interactions = self.interactions.lock().expect("cassette lock poisoned");
+        let mut played = played.lock().expect("cassette lock poisoned");
+        let matches =
+            |interaction: &&Interaction| interaction.method == method && interaction.url == url;
+
+        let next = interactions
+            .iter()
+            .enumerate()
+            .find(|(idx, interaction)| !played[*idx] && matches(interaction))
+            .or_else(|| {
+                interactions
+                    .iter()
+                    .enumerate()
+                    .rfind(|(_, interaction)| matches(interaction))
+            });
+        if let Some((idx, interaction)) = next {
+            played[idx] = true;
+            interaction
+                .response()
+                .map_err(|source| ApiError::client(source.into()))
+        } else {
+            Err(ApiError::client(CassetteError::NoInteraction {
+                method,
+                url,
+            }))
+        }
+    }
+
+    fn record_response(
+        &self,
+        method: String,
+        url: String,
+        rsp: &Response<Bytes>,
+    ) -> Result<(), ApiError<CassetteError<C::Error>>> {
+        let (body, raw) = match serde_json::from_slice(rsp.body()) {
+            Ok(body) => (body, false),
+            Err(_) => (
+                Value::String(String::from_utf8_lossy(rsp.body()).into_owned()),
+                true,
+            ),
+        };
+
+        let mut interactions = self.interactions.lock().expect("cassette lock poisoned");
+        interactions.push(Interaction {
+            method,
+            url,
+            status: rsp.status().as_u16(),
+            body,
+            raw,
+        });
+
+        let tape = Tape {
+            base_url: self.rest_endpoint("").ok().map(Into::into),
+            version_urls: self
+                .version_urls
+                .lock()
+                .expect("cassette lock poisoned")
+                .clone(),
+            interactions: interactions.clone(),
+        };
+        self.save(&tape).map_err(ApiError::client)
+    }
+
+    fn save(&self, tape: &Tape) -> Result<(), CassetteError<C::Error>> {
+        let io = |source| CassetteError::Io {
+            path: self.path.clone(),
+            source,
+        };
+        let data = serde_json::to_vec_pretty(tape).map_err(|source| CassetteError::Json {
+            path: self.path.clone(),
+            source,
+        })?;
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(io)?;
+        }
+        fs::write(&self.path, data).map_err(io)
+    }
+}
+
+/// Replace the value of the `access_key` in a URL.
+fn redact(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(url) => api::redact(&url).into(),
+        Err(_) => url.into(),
+    }
+}
+
+impl<C> RestClient for Cassette<C>
+where
+    C: RestClient,
+{
+    type Error = CassetteError<C::Error>;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        match &self.mode {
+            Mode::Record(client) => client
+                .rest_endpoint(endpoint)
+                .map_err(|err| err.map_client(|source| CassetteError::Client { source })),
+            Mode::Replay { base_url, .. } => Ok(base_url.join(endpoint)?),
+        }
+    }
+
+    fn versioned_rest_endpoint(
+        &self,
+        version: ApiVersion,
+        endpoint: &str,
+    ) -> Result<Url, ApiError<Self::Error>> {
+        let mut version_urls = self.version_urls.lock().expect("cassette lock poisoned");
+        let base_url = match &self.mode {
+            Mode::Record(client) => {
+                let base_url = client
+                    .versioned_rest_endpoint(version, "")
+                    .map_err(|err| err.map_client(|source| CassetteError::Client { source }))?;
+                version_urls.insert(version.as_str().into(), base_url.clone().into());
+                base_url
+            }
+            Mode::Replay { .. } => {
+                let base_url = version_urls
+                    .get(version.as_str())
+                    .ok_or_else(|| ApiError::client(CassetteError::NoVersion { version }))?;
+                Url::parse(base_url)?
+            }
+        };
+        Ok(base_url.join(endpoint)?)
+    }
+
+    fn get_auth(&self) -> Option<Auth> {
+        match &self.mode {
+            Mode::Record(client) => client.get_auth(),
+            Mode::Replay { .. } => Some(Auth::Token(REDACTED.into())),
+        }
+    }
+}
+
+impl<C> Client for Cassette<C>
+where
+    C: Client,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let (method, url) = Self::key(&request);
+        match &self.mode {
+            Mode::Record(client) => {
+                let rsp = client
+                    .rest(request, body)
+                    .map_err(|err| err.map_client(|source| CassetteError::Client { source }))?;
+                self.record_response(method, url, &rsp)?;
+                Ok(rsp)
+            }
+            Mode::Replay { played, .. } => self.play(played, method, url),
+        }
+    }
+}
+
+#[async_trait]
+impl<C> AsyncClient for Cassette<C>
+where
+    C: AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        let (method, url) = Self::key(&request);
+        match &self.mode {
+            Mode::Record(client) => {
+                let rsp = client
+                    .rest_async(request, body)
+                    .await
+                    .map_err(|err| err.map_client(|source| CassetteError::Client { source }))?;
+                self.record_response(method, url, &rsp)?;
+                Ok(rsp)
+            }
+            Mode::Replay { played, .. } => self.play(played, method, url),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use chrono::Utc;
+    use serde_json::json;
+
+    use crate::api::eod::Eod;
+    use crate::api::v2::tickerinfo::TickerInfo;
+    use crate::api::{self, ApiError, AsyncQuery, Query};
+    use crate::cassette::{Cassette, CassetteError, Interaction, Tape};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+    use crate::ApiVersion;
+
+    fn cassette_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!(
+                "marketstack-cassette-test-{}-{}",
+                std::process::id(),
+                Utc::now().timestamp_nanos_opt().unwrap_or_default(),
+            ))
+            .join(format!("{}.json", name))
+    }
+
+    fn write_tape(name: &str, interactions: Vec<Interaction>) -> PathBuf {
+        let path = cassette_path(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let tape = Tape {
+            base_url: Some("https://marketstack.host.invalid/v1/".into()),
+            version_urls: Default::default(),
+            interactions,
+        };
+        std::fs::write(&path, serde_json::to_vec(&tape).unwrap()).unwrap();
+        path
+    }
+
+    fn eod_interaction(body: serde_json::Value) -> Interaction {
+        Interaction {
+            method: "GET".into(),
+            url: "https://marketstack.host.invalid/v1/eod?symbols=AAPL&access_key=REDACTED".into(),
+            status: 200,
+            body,
+            raw: false,
+        }
+    }
+
+    #[test]
+    fn record_redacts_access_key() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("eod")
+            .add_query_params(&[("symbols", "AAPL"), ("access_key", "123")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!({"value": 1}));
+        let path = cassette_path("record");
+        let cassette = Cassette::record(client, &path);
+
+        let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+        let _: serde_json::Value = endpoint.query(&cassette).unwrap();
+
+        let data = std::fs::read_to_string(&path).unwrap();
+        assert!(!data.contains("123"));
+        let tape: Tape = serde_json::from_str(&data).unwrap();
+        assert_eq!(
+            tape.base_url.as_deref(),
+            Some("https://marketstack.host.invalid/v1/"),
+        );
+        assert_eq!(
+            tape.interactions,
+            vec![eod_interaction(json!({"value": 1}))],
+        );
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn replay_recorded() {
+        let path = write_tape("replay", vec![eod_interaction(json!({"value": 1}))]);
+        let cassette: Cassette<SingleTestClient> = Cassette::replay(&path).unwrap();
+
+        let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+        let value: serde_json::Value = endpoint.query(&cassette).unwrap();
+        assert_eq!(value, json!({"value": 1}));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn replay_in_order() {
+        let path = write_tape(
+            "replay_in_order",
+            vec![
+                eod_interaction(json!({"value": 1})),
+                eod_interaction(json!({"value": 2})),
+            ],
+        );
+        let cassette: Cassette<SingleTestClient> = Cassette::replay(&path).unwrap();
+
+        let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+        let first: serde_json::Value = endpoint.query(&cassette).unwrap();
+        let second: serde_json::Value = endpoint.query(&cassette).unwrap();
+        let again: serde_json::Value = endpoint.query(&cassette).unwrap();
+        assert_eq!(first, json!({"value": 1}));
+        assert_eq!(second, json!({"value": 2}));
+        assert_eq!(again, json!({"value": 2}));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn replay_raw_body() {
+        let mut interaction = eod_interaction(json!("not json"));
+        interaction.raw = true;
+        interaction.status = 500;
+        let path = write_tape("replay_raw_body", vec![interaction]);
+        let cassette: Cassette<SingleTestClient> = Cassette::replay(&path).unwrap();
+
+        let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+        let err = api::raw(endpoint).query(&cassette).unwrap_err();
+        if let ApiError::MarketstackService { status, data } = err {
+            assert_eq!(status, http::StatusCode::INTERNAL_SERVER_ERROR);
+            assert_eq!(data, b"not json");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn replay_missing() {
+        let path = write_tape("replay_missing", vec![eod_interaction(json!({}))]);
+        let cassette: Cassette<SingleTestClient> = Cassette::replay(&path).unwrap();
+
+        let endpoint = Eod::builder().symbol("MSFT").build().unwrap();
+        let err = endpoint
+            .query(&cassette)
+            .map(|_: serde_json::Value| ())
+            .unwrap_err();
+        if let ApiError::Client {
+            source: CassetteError::NoInteraction { method, url },
+        } = err
+        {
+            assert_eq!(method, "GET");
+            assert_eq!(
+                url,
+                "https://marketstack.host.invalid/v1/eod?symbols=MSFT&access_key=REDACTED",
+            );
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn record_and_replay_version() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("tickerinfo")
+            .api_version(ApiVersion::V2)
+            .add_query_params(&[("ticker", "AAPL")])
+            .build()
+            .unwrap();
+        let client = SingleTestClient::new_json(endpoint, &json!({"value": 1}));
+        let path = cassette_path("version");
+        let cassette = Cassette::record(client, &path);
+
+        let endpoint = TickerInfo::builder().ticker("AAPL").build().unwrap();
+        let _: serde_json::Value = endpoint.query(&cassette).unwrap();
+
+        let tape: Tape = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(
+            tape.version_urls.get("v2").map(String::as_str),
+            Some("https://marketstack.host.invalid/v2/"),
+        );
+        assert_eq!(
+            tape.interactions[0].url,
+            "https://marketstack.host.invalid/v2/tickerinfo?ticker=AAPL&access_key=REDACTED",
+        );
+
+        let cassette: Cassette<SingleTestClient> = Cassette::replay(&path).unwrap();
+        let value: serde_json::Value = endpoint.query(&cassette).unwrap();
+        assert_eq!(value, json!({"value": 1}));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn replay_missing_version() {
+        let path = write_tape("replay_missing_version", vec![eod_interaction(json!({}))]);
+        let cassette: Cassette<SingleTestClient> = Cassette::replay(&path).unwrap();
+
+        let endpoint = TickerInfo::builder().ticker("AAPL").build().unwrap();
+        let err = endpoint
+            .query(&cassette)
+            .map(|_: serde_json::Value| ())
+            .unwrap_err();
+        assert!(
+            matches!(
+                err,
+                ApiError::Client {
+                    source: CassetteError::NoVersion {
+                        version: ApiVersion::V2,
+                    },
+                },
+            ),
+            "unexpected error: {}",
+            err,
+        );
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn replay_no_file() {
+        let res = Cassette::<SingleTestClient>::replay(cassette_path("nonexistent"));
+        assert!(matches!(res, Err(CassetteError::Io { .. })));
+    }
+
+    #[tokio::test]
+    async fn replay_async() {
+        let path = write_tape("replay_async", vec![eod_interaction(json!({"value": 1}))]);
+        let cassette: Cassette<SingleTestClient> = Cassette::replay(&path).unwrap();
+
+        let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+        let value: serde_json::Value = endpoint.query_async(&cassette).await.unwrap();
+        assert_eq!(value, json!({"value": 1}));
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
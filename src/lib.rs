@@ -2,14 +2,38 @@
 
 pub mod api;
 mod auth;
+mod cache;
+mod cassette;
 mod marketstack;
+mod rate_limit;
+mod retry;
 pub mod types;
 
-pub use crate::auth::AuthError;
+pub use crate::auth::{AuthError, ConnectionError};
+pub use crate::cache::{
+    CacheConfig, CacheConfigBuilder, CacheConfigBuilderError, CacheEntry, CacheStore, CachedClient,
+    DiskStore, MemoryStore, Ttl,
+};
+pub use crate::cassette::{Cassette, CassetteError, Interaction};
 pub use crate::marketstack::{
-    AsyncMarketstack, Marketstack, MarketstackBuilder, MarketstackError, RestError,
+    ApiVersion, AsyncMarketstack, Marketstack, MarketstackBuilder, MarketstackError, RestError,
+};
+pub use crate::rate_limit::{RateLimit, RateLimitBuilder, RateLimitBuilderError, RateLimited};
+pub use crate::retry::{
+    is_transient, Jitter, RetryCause, RetryClassifier, RetryPolicy, RetryPolicyBuilder,
+    RetryPolicyBuilderError, Retrying,
+};
+// `types::v2` is left out so that it is not confused with `api::v2`.
+pub use crate::types::{
+    CurrenciesData, CurrenciesDataItem, DividendsData, DividendsDataItem, EodData, EodDataItem,
+    EodRow, ExchangeData, ExchangeTicker, ExchangesData, ExchangesDataItem, ExchangesEodData,
+    ExchangesEodDataInner, ExchangesIntradayData, ExchangesIntradayDataInner, ExchangesResponse,
+    ExchangesTickersData, ExchangesTickersDataInner, IntradayData, IntradayDataItem, Paginated,
+    PaginationInfo, SplitsData, SplitsDataItem, StockExchange, TickerData, TickerEodDataInner,
+    TickerIntradayDataInner, TickersData, TickersDataItem, TickersDividendsData, TickersEodData,
+    TickersEodLatestData, TickersIntradayData, TickersIntradayLatestData, TickersResponse,
+    TickersSplitsData, TimezonesData, TimezonesDataItem,
 };
-pub use crate::types::*;
 
-#[cfg(test)]
-mod test;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
@@ -1,17 +1,21 @@
 use std::convert::TryInto;
 use std::fmt::{self, Debug};
+use std::time::Duration;
 
 use async_trait::async_trait;
 use bytes::Bytes;
 use http::{request, Response as HttpResponse};
-use log::{debug, error};
+use log::debug;
 use reqwest::blocking::Client;
+use reqwest::header::HeaderMap;
 use reqwest::Client as AsyncClient;
+use reqwest::{Certificate, Identity, Proxy};
 use thiserror::Error;
 use url::Url;
 
 use crate::api;
-use crate::auth::Auth;
+use crate::auth::{Auth, ConnectionError};
+use crate::retry::RetryPolicy;
 
 #[derive(Debug, Error)]
 #[non_exhaustive]
@@ -56,6 +60,11 @@ pub enum MarketstackError {
         #[from]
         source: api::ApiError<RestError>,
     },
+    #[error("{}", source)]
+    Connection {
+        #[from]
+        source: ConnectionError<RestError>,
+    },
 }
 
 type MarketstackResult<T> = Result<T, MarketstackError>;
@@ -65,16 +74,22 @@ type MarketstackResult<T> = Result<T, MarketstackError>;
 pub struct Marketstack {
     /// The client to use for API calls.
     client: Client,
-    /// The base URL to use for API calls.
-    rest_url: Url,
+    /// The base URL to use for API calls, without the API version.
+    base_url: Url,
+    /// The version of the API to use for endpoints which do not ask for one.
+    api_version: ApiVersion,
     /// The authentication information to use when communicating with Marketstack.
     auth: Auth,
+    /// How failed requests are retried, if at all.
+    retry: Option<RetryPolicy>,
 }
 
 impl Debug for Marketstack {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Marketstack")
-            .field("rest_url", &self.rest_url)
+            .field("base_url", &self.base_url)
+            .field("api_version", &self.api_version)
+            .field("retry", &self.retry)
             .finish()
     }
 }
@@ -89,7 +104,7 @@ impl Marketstack {
         H: AsRef<str>,
         T: Into<String>,
     {
-        Self::new_impl("https", host.as_ref(), Auth::Token(token.into()))
+        Self::builder(host.as_ref(), token).build()
     }
 
     /// Create a new non-SSL Marketstack API representation.
@@ -100,30 +115,37 @@ impl Marketstack {
         H: AsRef<str>,
         T: Into<String>,
     {
-        Self::new_impl("http", host.as_ref(), Auth::Token(token.into()))
+        Self::builder(host.as_ref(), token).insecure().build()
     }
 
     /// Internal method to create a new Marketstack client.
-    fn new_impl(protocol: &str, host: &str, auth: Auth) -> MarketstackResult<Self> {
-        let rest_url = Url::parse(&format!("{}://{}/v1/", protocol, host))?;
-
-        // NOTE: If cert validation is implemented / required, then add it here as `ClientCert`
-        let client = Client::builder()
-            .danger_accept_invalid_certs(true)
-            .build()?;
+    fn new_impl(builder: &MarketstackBuilder) -> MarketstackResult<Self> {
+        let base_url = builder.root_url()?;
+        let client = builder.blocking_client()?;
 
         let api = Marketstack {
             client,
-            rest_url,
-            auth,
+            base_url,
+            api_version: builder.api_version,
+            auth: builder.token.clone(),
+            retry: builder.retry.clone(),
         };
 
         // Ensure the API is working.
-        api.auth.check_connection(&api)?;
+        if builder.check_connection {
+            api.verify()?;
+        }
 
         Ok(api)
     }
 
+    /// Check that Marketstack is reachable and accepts the token.
+    ///
+    /// This makes one small request, which counts against the monthly quota.
+    pub fn verify(&self) -> Result<(), ConnectionError<RestError>> {
+        self.auth.check_connection(self)
+    }
+
     /// Create a new Marketstack API client builder.
     pub fn builder<H, T>(host: H, token: T) -> MarketstackBuilder
     where
@@ -156,11 +178,131 @@ impl Marketstack {
     }
 }
 
+/// Versions of the Marketstack API.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ApiVersion {
+    /// Version 1, at `/v1/`.
+    #[default]
+    V1,
+    /// Version 2, at `/v2/`.
+    V2,
+}
+
+impl ApiVersion {
+    /// The path segment for the version.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ApiVersion::V1 => "v1",
+            ApiVersion::V2 => "v2",
+        }
+    }
+
+    /// The URL of the version below a base URL ending in `/`.
+    fn url(self, base_url: &Url) -> Result<Url, url::ParseError> {
+        base_url.join(&format!("{}/", self.as_str()))
+    }
+}
+
+/// A certificate in either PEM or DER encoding.
+#[derive(Clone)]
+enum EncodedCert {
+    Pem(Vec<u8>),
+    Der(Vec<u8>),
+}
+
+impl EncodedCert {
+    fn to_certificate(&self) -> reqwest::Result<Certificate> {
+        match self {
+            EncodedCert::Pem(pem) => Certificate::from_pem(pem),
+            EncodedCert::Der(der) => Certificate::from_der(der),
+        }
+    }
+}
+
+/// A client certificate used to authenticate to the server.
+#[derive(Clone)]
+enum ClientCert {
+    None,
+    Pkcs12 { der: Vec<u8>, password: String },
+    Pkcs8 { cert: Vec<u8>, key: Vec<u8> },
+}
+
+impl ClientCert {
+    fn to_identity(&self) -> reqwest::Result<Option<Identity>> {
+        match self {
+            ClientCert::None => Ok(None),
+            ClientCert::Pkcs12 { der, password } => {
+                Identity::from_pkcs12_der(der, password).map(Some)
+            }
+            ClientCert::Pkcs8 { cert, key } => Identity::from_pkcs8_pem(cert, key).map(Some),
+        }
+    }
+}
+
+/// Apply the settings shared by the blocking and asynchronous `reqwest` client builders.
+///
+/// The two builders are distinct types with the same methods, so a macro is used instead of a
+/// generic function. Evaluates to the configured builder, or returns early with an error.
+macro_rules! configure_client {
+    ($builder:expr, $client:expr) => {{
+        let mut client = $client
+            .danger_accept_invalid_certs($builder.accept_invalid_certs)
+            .default_headers($builder.headers.clone())
+            .gzip($builder.gzip)
+            .brotli($builder.brotli);
+        for cert in $builder.root_certificates()? {
+            client = client.add_root_certificate(cert);
+        }
+        if let Some(identity) = $builder.identity.to_identity()? {
+            client = client.identity(identity);
+        }
+        for proxy in &$builder.proxies {
+            client = client.proxy(proxy.clone());
+        }
+        if let Some(user_agent) = &$builder.user_agent {
+            client = client.user_agent(user_agent);
+        }
+        if let Some(timeout) = $builder.connect_timeout {
+            client = client.connect_timeout(timeout);
+        }
+        if let Some(timeout) = $builder.timeout {
+            client = client.timeout(timeout);
+        }
+        if let Some(max) = $builder.pool_max_idle_per_host {
+            client = client.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = $builder.pool_idle_timeout {
+            client = client.pool_idle_timeout(timeout);
+        }
+        client
+    }};
+}
+
 /// Builder pattern implementation for Marketstack and AsyncMarketstack.
 pub struct MarketstackBuilder {
     protocol: &'static str,
     host: String,
+    base_url: Option<Url>,
+    api_version: ApiVersion,
     token: Auth,
+    retry: Option<RetryPolicy>,
+    root_certs: Vec<EncodedCert>,
+    identity: ClientCert,
+    accept_invalid_certs: bool,
+    check_connection: bool,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxies: Vec<Proxy>,
+    user_agent: Option<String>,
+    headers: HeaderMap,
+    gzip: bool,
+    brotli: bool,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    http_client: Option<Client>,
+    async_http_client: Option<AsyncClient>,
 }
 
 impl MarketstackBuilder {
@@ -173,7 +315,26 @@ impl MarketstackBuilder {
         Self {
             protocol: "https",
             host: host.into(),
+            base_url: None,
+            api_version: ApiVersion::default(),
             token: Auth::Token(token.into()),
+            retry: None,
+            root_certs: Vec::new(),
+            identity: ClientCert::None,
+            accept_invalid_certs: false,
+            check_connection: true,
+            connect_timeout: None,
+            read_timeout: None,
+            timeout: None,
+            proxies: Vec::new(),
+            user_agent: None,
+            headers: HeaderMap::new(),
+            gzip: true,
+            brotli: true,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            http_client: None,
+            async_http_client: None,
         }
     }
 
@@ -183,12 +344,214 @@ impl MarketstackBuilder {
         self
     }
 
+    /// Send requests to a base URL other than `https://{host}/`.
+    ///
+    /// The URL may include a port and a path prefix, e.g. `http://127.0.0.1:8080/marketstack/`.
+    /// The API version is appended to it, and both the host and `insecure` are ignored.
+    pub fn base_url(&mut self, url: Url) -> &mut Self {
+        self.base_url = Some(url);
+        self
+    }
+
+    /// The version of the API to use. Defaults to `v1`.
+    pub fn api_version(&mut self, version: ApiVersion) -> &mut Self {
+        self.api_version = version;
+        self
+    }
+
+    /// Retry failed requests according to the given policy.
+    ///
+    /// By default, every request is sent only once.
+    pub fn retry(&mut self, policy: RetryPolicy) -> &mut Self {
+        self.retry = Some(policy);
+        self
+    }
+
+    /// Trust a root certificate in PEM encoding in addition to the system roots.
+    ///
+    /// Useful when talking to Marketstack through a proxy with its own certificate authority.
+    pub fn add_root_certificate_pem(&mut self, pem: &[u8]) -> &mut Self {
+        self.root_certs.push(EncodedCert::Pem(pem.into()));
+        self
+    }
+
+    /// Trust a root certificate in DER encoding in addition to the system roots.
+    pub fn add_root_certificate_der(&mut self, der: &[u8]) -> &mut Self {
+        self.root_certs.push(EncodedCert::Der(der.into()));
+        self
+    }
+
+    /// Authenticate with a client certificate from a PKCS #12 archive in DER encoding.
+    pub fn client_identity_from_pkcs12_der(&mut self, der: &[u8], password: &str) -> &mut Self {
+        self.identity = ClientCert::Pkcs12 {
+            der: der.into(),
+            password: password.into(),
+        };
+        self
+    }
+
+    /// Authenticate with a client certificate chain and PKCS #8 private key in PEM encoding.
+    pub fn client_identity_from_pkcs8_pem(&mut self, cert: &[u8], key: &[u8]) -> &mut Self {
+        self.identity = ClientCert::Pkcs8 {
+            cert: cert.into(),
+            key: key.into(),
+        };
+        self
+    }
+
+    /// Accept any certificate presented by the server, including self-signed and expired ones.
+    ///
+    /// This makes the connection vulnerable to man-in-the-middle attacks and should only be
+    /// used for testing. Certificates are validated by default.
+    pub fn danger_accept_invalid_certs(&mut self) -> &mut Self {
+        self.accept_invalid_certs = true;
+        self
+    }
+
+    /// Timeout for establishing a connection.
+    pub fn connect_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for each read from a connection.
+    ///
+    /// Only supported by the asynchronous client; the blocking client bounds reads through
+    /// `timeout` instead.
+    pub fn read_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Timeout for a whole request, from connecting until the response body has been read.
+    ///
+    /// The blocking client uses a default of 30 seconds; the asynchronous client has none.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests through a proxy.
+    ///
+    /// HTTP, HTTPS and SOCKS5 proxies are supported, e.g. `Proxy::all("socks5://127.0.0.1:1080")`.
+    /// May be called multiple times; the first proxy matching a request is used. Without any,
+    /// the proxy is taken from the system environment.
+    pub fn proxy(&mut self, proxy: Proxy) -> &mut Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// The `User-Agent` header to send with every request.
+    pub fn user_agent<U>(&mut self, user_agent: U) -> &mut Self
+    where
+        U: Into<String>,
+    {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Headers to send with every request, in addition to any set before.
+    pub fn default_headers(&mut self, headers: HeaderMap) -> &mut Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    /// Whether to accept gzip-compressed responses. Enabled by default.
+    pub fn gzip(&mut self, enable: bool) -> &mut Self {
+        self.gzip = enable;
+        self
+    }
+
+    /// Whether to accept brotli-compressed responses. Enabled by default.
+    pub fn brotli(&mut self, enable: bool) -> &mut Self {
+        self.brotli = enable;
+        self
+    }
+
+    /// The maximum number of idle connections kept open per host.
+    pub fn pool_max_idle_per_host(&mut self, max: usize) -> &mut Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// How long idle connections are kept open.
+    pub fn pool_idle_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Use a pre-built HTTP client for `build`.
+    ///
+    /// The TLS and HTTP settings of this builder are ignored in favour of those of the client.
+    pub fn http_client(&mut self, client: Client) -> &mut Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Use a pre-built HTTP client for `build_async`.
+    ///
+    /// The TLS and HTTP settings of this builder are ignored in favour of those of the client.
+    pub fn async_http_client(&mut self, client: AsyncClient) -> &mut Self {
+        self.async_http_client = Some(client);
+        self
+    }
+
+    /// Do not check the connection when building the client.
+    ///
+    /// By default, building a client makes a request to ensure that Marketstack is reachable and
+    /// accepts the token. Lazy clients skip this; use `verify` to check explicitly.
+    pub fn lazy(&mut self) -> &mut Self {
+        self.check_connection = false;
+        self
+    }
+
     pub fn build(&self) -> MarketstackResult<Marketstack> {
-        Marketstack::new_impl(self.protocol, &self.host, self.token.clone())
+        Marketstack::new_impl(self)
     }
 
     pub async fn build_async(&self) -> MarketstackResult<AsyncMarketstack> {
-        AsyncMarketstack::new_impl(self.protocol, &self.host, self.token.clone()).await
+        AsyncMarketstack::new_impl(self).await
+    }
+
+    fn root_url(&self) -> MarketstackResult<Url> {
+        let mut base = if let Some(base_url) = &self.base_url {
+            base_url.clone()
+        } else {
+            Url::parse(&format!("{}://{}/", self.protocol, self.host))?
+        };
+        // Without a trailing slash, the last segment of the path would be replaced on joining.
+        if !base.path().ends_with('/') {
+            let path = format!("{}/", base.path());
+            base.set_path(&path);
+        }
+        Ok(base)
+    }
+
+    fn blocking_client(&self) -> reqwest::Result<Client> {
+        if let Some(client) = &self.http_client {
+            return Ok(client.clone());
+        }
+
+        configure_client!(self, Client::builder()).build()
+    }
+
+    fn async_client(&self) -> reqwest::Result<AsyncClient> {
+        if let Some(client) = &self.async_http_client {
+            return Ok(client.clone());
+        }
+
+        let mut client = configure_client!(self, AsyncClient::builder());
+        if let Some(timeout) = self.read_timeout {
+            client = client.read_timeout(timeout);
+        }
+        client.build()
+    }
+
+    fn root_certificates(&self) -> reqwest::Result<Vec<Certificate>> {
+        self.root_certs
+            .iter()
+            .map(EncodedCert::to_certificate)
+            .collect()
     }
 }
 
@@ -196,7 +559,15 @@ impl api::RestClient for Marketstack {
     type Error = RestError;
 
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
-        Ok(self.rest_url.join(endpoint)?)
+        self.versioned_rest_endpoint(self.api_version, endpoint)
+    }
+
+    fn versioned_rest_endpoint(
+        &self,
+        version: ApiVersion,
+        endpoint: &str,
+    ) -> Result<Url, api::ApiError<Self::Error>> {
+        Ok(version.url(&self.base_url)?.join(endpoint)?)
     }
 
     fn get_auth(&self) -> Option<Auth> {
@@ -210,7 +581,13 @@ impl api::Client for Marketstack {
         request: request::Builder,
         body: Vec<u8>,
     ) -> Result<HttpResponse<Bytes>, api::ApiError<Self::Error>> {
-        self.rest_simple(request, body)
+        if let Some(retry) = &self.retry {
+            retry.send(request, body, |request, body| {
+                self.rest_simple(request, body)
+            })
+        } else {
+            self.rest_simple(request, body)
+        }
     }
 }
 
@@ -219,16 +596,22 @@ impl api::Client for Marketstack {
 pub struct AsyncMarketstack {
     /// The client to use for API calls.
     client: reqwest::Client,
-    /// The base URL to use for API calls.
-    rest_url: Url,
+    /// The base URL to use for API calls, without the API version.
+    base_url: Url,
+    /// The version of the API to use for endpoints which do not ask for one.
+    api_version: ApiVersion,
     /// The authentication information to use when communicating with Marketstack.
     auth: Auth,
+    /// How failed requests are retried, if at all.
+    retry: Option<RetryPolicy>,
 }
 
 impl Debug for AsyncMarketstack {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("AsyncMarketstack")
-            .field("rest_url", &self.rest_url)
+            .field("base_url", &self.base_url)
+            .field("api_version", &self.api_version)
+            .field("retry", &self.retry)
             .finish()
     }
 }
@@ -238,8 +621,16 @@ impl api::RestClient for AsyncMarketstack {
     type Error = RestError;
 
     fn rest_endpoint(&self, endpoint: &str) -> Result<Url, api::ApiError<Self::Error>> {
+        self.versioned_rest_endpoint(self.api_version, endpoint)
+    }
+
+    fn versioned_rest_endpoint(
+        &self,
+        version: ApiVersion,
+        endpoint: &str,
+    ) -> Result<Url, api::ApiError<Self::Error>> {
         debug!(target: "marketstack", "REST api call {}", endpoint);
-        Ok(self.rest_url.join(endpoint)?)
+        Ok(version.url(&self.base_url)?.join(endpoint)?)
     }
 
     fn get_auth(&self) -> Option<Auth> {
@@ -254,31 +645,47 @@ impl api::AsyncClient for AsyncMarketstack {
         request: http::request::Builder,
         body: Vec<u8>,
     ) -> Result<HttpResponse<Bytes>, api::ApiError<<Self as api::RestClient>::Error>> {
-        self.rest_async_simple(request, body).await
+        if let Some(retry) = &self.retry {
+            retry
+                .send_async(request, body, |request, body| {
+                    self.rest_async_simple(request, body)
+                })
+                .await
+        } else {
+            self.rest_async_simple(request, body).await
+        }
     }
 }
 
 impl AsyncMarketstack {
     /// Internal method to create a new Marketstack client.
-    async fn new_impl(protocol: &str, host: &str, auth: Auth) -> MarketstackResult<Self> {
-        let rest_url = Url::parse(&format!("{}://{}/v1/", protocol, host))?;
-
-        let client = AsyncClient::builder()
-            .danger_accept_invalid_certs(true)
-            .build()?;
+    async fn new_impl(builder: &MarketstackBuilder) -> MarketstackResult<Self> {
+        let base_url = builder.root_url()?;
+        let client = builder.async_client()?;
 
         let api = AsyncMarketstack {
             client,
-            rest_url,
-            auth,
+            base_url,
+            api_version: builder.api_version,
+            auth: builder.token.clone(),
+            retry: builder.retry.clone(),
         };
 
         // Ensure the API is working.
-        api.auth.check_connection_async(&api).await?;
+        if builder.check_connection {
+            api.verify().await?;
+        }
 
         Ok(api)
     }
 
+    /// Check that Marketstack is reachable and accepts the token.
+    ///
+    /// This makes one small request, which counts against the monthly quota.
+    pub async fn verify(&self) -> Result<(), ConnectionError<RestError>> {
+        self.auth.check_connection_async(self).await
+    }
+
     async fn rest_async_simple(
         &self,
         request: http::request::Builder,
@@ -311,7 +718,9 @@ impl AsyncMarketstack {
         H: AsRef<str>,
         T: Into<String>,
     {
-        Self::new_impl("https", host.as_ref(), Auth::Token(token.into())).await
+        MarketstackBuilder::new(host.as_ref(), token)
+            .build_async()
+            .await
     }
 
     /// Create a new non-SSL AsyncMarketstack API representation.
@@ -322,6 +731,9 @@ impl AsyncMarketstack {
         H: AsRef<str>,
         T: Into<String>,
     {
-        Self::new_impl("http", host.as_ref(), Auth::Token(token.into())).await
+        MarketstackBuilder::new(host.as_ref(), token)
+            .insecure()
+            .build_async()
+            .await
     }
 }
@@ -0,0 +1,484 @@
+//! Client-side rate limiting.
+//!
+//! Marketstack enforces both a per-second rate limit and a monthly request budget which depends
+//! on the subscription plan. [`RateLimited`] wraps any [`Client`] or [`AsyncClient`] and holds
+//! requests back until they fit within the configured [`RateLimit`].
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use marketstack::api::{self, Query};
+//! use marketstack::api::eod::Eod;
+//! use marketstack::{EodData, Marketstack, RateLimit, RateLimited};
+//!
+//! let client = Marketstack::new("api.marketstack.com", "private-token").unwrap();
+//!
+//! // Allow 5 requests per second, and 10,000 requests per month before failing.
+//! let limit = RateLimit::builder()
+//!     .per_second(5)
+//!     .monthly_budget(10_000)
+//!     .fail_fast(true)
+//!     .build()
+//!     .unwrap();
+//! let client = RateLimited::new(client, limit);
+//!
+//! let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+//! let eod_data: EodData = endpoint.query(&client).unwrap();
+//! ```
+
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{Datelike, NaiveDate, NaiveTime, Utc};
+use derive_builder::Builder;
+use http::request::Builder as RequestBuilder;
+use http::Response;
+use url::Url;
+
+use crate::api::{ApiError, AsyncClient, Client, RestClient};
+use crate::auth::Auth;
+use crate::ApiVersion;
+
+/// The request quotas of a Marketstack plan.
+#[derive(Debug, Builder, Clone)]
+#[builder(setter(strip_option), build_fn(validate = "Self::validate"))]
+pub struct RateLimit {
+    /// Requests allowed per second. This is also the largest burst allowed.
+    #[builder(default)]
+    per_second: Option<u32>,
+    /// Requests allowed per calendar month (UTC).
+    #[builder(default)]
+    monthly_budget: Option<u64>,
+    /// Requests already made this month, e.g. by an earlier process.
+    #[builder(default)]
+    used_this_month: u64,
+    /// Return [`ApiError::QuotaExhausted`] once the monthly budget is gone, rather than waiting
+    /// for the next month.
+    #[builder(default)]
+    fail_fast: bool,
+}
+
+impl RateLimit {
+    /// Create a builder for the rate limit.
+    pub fn builder() -> RateLimitBuilder {
+        RateLimitBuilder::default()
+    }
+}
+
+impl RateLimitBuilder {
+    /// Check that `RateLimit` contains sensible values.
+    fn validate(&self) -> Result<(), String> {
+        if let Some(Some(0)) = self.per_second {
+            Err("`per_second` must be at least 1".into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The outcome of asking the limiter for a slot.
+#[derive(Debug, PartialEq, Eq)]
+enum Slot {
+    /// The request may be sent now.
+    Ready,
+    /// The request must wait before asking again.
+    Wait(Duration),
+    /// The monthly budget is gone.
+    Exhausted(u64),
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// Tokens currently available for the per-second limit.
+    tokens: f64,
+    /// When the tokens were last refilled.
+    refilled: Instant,
+    /// The first day of the month `used` counts towards.
+    month: NaiveDate,
+    /// Requests made during `month`.
+    used: u64,
+}
+
+/// A token bucket enforcing a [`RateLimit`].
+#[derive(Debug)]
+struct Limiter {
+    limit: RateLimit,
+    bucket: Mutex<Bucket>,
+}
+
+fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1)
+        .expect("the first day of a month is always valid")
+}
+
+fn next_month(month: NaiveDate) -> NaiveDate {
+    if month.month() == 12 {
+        NaiveDate::from_ymd_opt(month.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(month.year(), month.month() + 1, 1)
+    }
+    .expect("the first day of a month is always valid")
+}
+
+impl Limiter {
+    fn new(limit: RateLimit, now: Instant, today: NaiveDate) -> Self {
+        let bucket = Bucket {
+            tokens: limit.per_second.unwrap_or(0).into(),
+            refilled: now,
+            month: month_start(today),
+            used: limit.used_this_month,
+        };
+
+        Self {
+            limit,
+            bucket: Mutex::new(bucket),
+        }
+    }
+
+    fn acquire_now(&self) -> Slot {
+        let now = Utc::now();
+        self.acquire_at(Instant::now(), now.date_naive(), now.time())
+    }
+
+    fn acquire_at(&self, now: Instant, today: NaiveDate, time: NaiveTime) -> Slot {
+        let mut bucket = self.bucket.lock().expect("rate limiter lock poisoned");
+
+        if month_start(today) != bucket.month {
+            bucket.month = month_start(today);
+            bucket.used = 0;
+        }
+        if let Some(budget) = self.limit.monthly_budget {
+            if bucket.used >= budget {
+                if self.limit.fail_fast {
+                    return Slot::Exhausted(budget);
+                }
+                let reset = next_month(bucket.month).and_hms_opt(0, 0, 0).unwrap();
+                let wait = (reset - today.and_time(time)).to_std().unwrap_or_default();
+                return Slot::Wait(wait);
+            }
+        }
+
+        if let Some(per_second) = self.limit.per_second {
+            let rate = f64::from(per_second);
+            let elapsed = now.saturating_duration_since(bucket.refilled);
+            bucket.tokens = (bucket.tokens + elapsed.as_secs_f64() * rate).min(rate);
+            bucket.refilled = now;
+
+            if bucket.tokens < 1.0 {
+                return Slot::Wait(Duration::from_secs_f64((1.0 - bucket.tokens) / rate));
+            }
+            bucket.tokens -= 1.0;
+        }
+
+        bucket.used += 1;
+        Slot::Ready
+    }
+
+    fn acquire<E>(&self) -> Result<(), ApiError<E>>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        loop {
+            match self.acquire_now() {
+                Slot::Ready => return Ok(()),
+                Slot::Wait(wait) => thread::sleep(wait),
+                Slot::Exhausted(budget) => return Err(ApiError::QuotaExhausted { budget }),
+            }
+        }
+    }
+
+    async fn acquire_async<E>(&self) -> Result<(), ApiError<E>>
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        loop {
+            match self.acquire_now() {
+                Slot::Ready => return Ok(()),
+                Slot::Wait(wait) => tokio::time::sleep(wait).await,
+                Slot::Exhausted(budget) => return Err(ApiError::QuotaExhausted { budget }),
+            }
+        }
+    }
+
+    fn used(&self) -> u64 {
+        self.bucket.lock().expect("rate limiter lock poisoned").used
+    }
+}
+
+/// A client wrapper which keeps requests within a [`RateLimit`].
+///
+/// Clones share the same quotas, so one `RateLimited` client may be cloned across tasks.
+///
+/// Every request takes a single slot, so retries made by the wrapped client go uncounted. Rather
+/// than configuring [`MarketstackBuilder::retry`](crate::MarketstackBuilder::retry), wrap this
+/// client with [`Retrying`](crate::Retrying) so that each attempt waits for a slot of its own.
+#[derive(Debug, Clone)]
+pub struct RateLimited<C> {
+    client: C,
+    limiter: Arc<Limiter>,
+}
+
+impl<C> RateLimited<C> {
+    /// Wrap a client with a rate limit.
+    pub fn new(client: C, limit: RateLimit) -> Self {
+        let limiter = Limiter::new(limit, Instant::now(), Utc::now().date_naive());
+
+        Self {
+            client,
+            limiter: Arc::new(limiter),
+        }
+    }
+
+    /// The number of requests sent through this client during the current month.
+    pub fn used_this_month(&self) -> u64 {
+        self.limiter.used()
+    }
+
+    /// The wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.client
+    }
+}
+
+impl<C> RestClient for RateLimited<C>
+where
+    C: RestClient,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.rest_endpoint(endpoint)
+    }
+
+    fn versioned_rest_endpoint(
+        &self,
+        version: ApiVersion,
+        endpoint: &str,
+    ) -> Result<Url, ApiError<Self::Error>> {
+        self.client.versioned_rest_endpoint(version, endpoint)
+    }
+
+    fn get_auth(&self) -> Option<Auth> {
+        self.client.get_auth()
+    }
+}
+
+impl<C> Client for RateLimited<C>
+where
+    C: Client,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.limiter.acquire()?;
+        self.client.rest(request, body)
+    }
+}
+
+#[async_trait]
+impl<C> AsyncClient for RateLimited<C>
+where
+    C: AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.limiter.acquire_async().await?;
+        self.client.rest_async(request, body).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use chrono::{NaiveDate, NaiveTime};
+    use http::StatusCode;
+
+    use crate::api::eod::Eod;
+    use crate::api::{self, ApiError, AsyncQuery, Query};
+    use crate::rate_limit::{Limiter, RateLimit, RateLimited, Slot};
+    use crate::retry::{Jitter, RetryPolicy, Retrying};
+    use crate::testing::{ExpectedUrl, SingleTestClient};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn midnight() -> NaiveTime {
+        NaiveTime::from_hms_opt(0, 0, 0).unwrap()
+    }
+
+    fn eod_client() -> SingleTestClient {
+        let endpoint = ExpectedUrl::builder().endpoint("eod").build().unwrap();
+        SingleTestClient::new_raw(endpoint, "")
+    }
+
+    #[test]
+    fn rate_limit_defaults_are_sufficient() {
+        RateLimit::builder().build().unwrap();
+    }
+
+    #[test]
+    fn rate_limit_zero_per_second() {
+        let err = RateLimit::builder().per_second(0).build().unwrap_err();
+        assert!(err.to_string().contains("per_second"));
+    }
+
+    #[test]
+    fn rate_limit_burst_then_wait() {
+        let limit = RateLimit::builder().per_second(2).build().unwrap();
+        let start = Instant::now();
+        let today = date(2023, 10, 5);
+        let limiter = Limiter::new(limit, start, today);
+
+        assert_eq!(limiter.acquire_at(start, today, midnight()), Slot::Ready);
+        assert_eq!(limiter.acquire_at(start, today, midnight()), Slot::Ready);
+        assert_eq!(
+            limiter.acquire_at(start, today, midnight()),
+            Slot::Wait(Duration::from_millis(500)),
+        );
+
+        let later = start + Duration::from_millis(500);
+        assert_eq!(limiter.acquire_at(later, today, midnight()), Slot::Ready);
+        assert_eq!(limiter.used(), 3);
+    }
+
+    #[test]
+    fn rate_limit_refill_is_capped() {
+        let limit = RateLimit::builder().per_second(2).build().unwrap();
+        let start = Instant::now();
+        let today = date(2023, 10, 5);
+        let limiter = Limiter::new(limit, start, today);
+
+        let later = start + Duration::from_secs(60);
+        assert_eq!(limiter.acquire_at(later, today, midnight()), Slot::Ready);
+        assert_eq!(limiter.acquire_at(later, today, midnight()), Slot::Ready);
+        assert!(matches!(
+            limiter.acquire_at(later, today, midnight()),
+            Slot::Wait(_)
+        ));
+    }
+
+    #[test]
+    fn rate_limit_monthly_budget() {
+        let limit = RateLimit::builder()
+            .monthly_budget(3)
+            .used_this_month(1)
+            .fail_fast(true)
+            .build()
+            .unwrap();
+        let now = Instant::now();
+        let today = date(2023, 12, 30);
+        let limiter = Limiter::new(limit, now, today);
+
+        assert_eq!(limiter.acquire_at(now, today, midnight()), Slot::Ready);
+        assert_eq!(limiter.acquire_at(now, today, midnight()), Slot::Ready);
+        assert_eq!(
+            limiter.acquire_at(now, today, midnight()),
+            Slot::Exhausted(3)
+        );
+
+        // The budget is renewed once the month is over.
+        let next_month = date(2024, 1, 1);
+        assert_eq!(limiter.acquire_at(now, next_month, midnight()), Slot::Ready);
+        assert_eq!(limiter.used(), 1);
+    }
+
+    #[test]
+    fn rate_limit_monthly_budget_wait() {
+        let limit = RateLimit::builder().monthly_budget(0).build().unwrap();
+        let now = Instant::now();
+        let today = date(2023, 2, 28);
+        let limiter = Limiter::new(limit, now, today);
+
+        // Without `fail_fast`, the limiter waits for the budget to be renewed.
+        let noon = NaiveTime::from_hms_opt(12, 0, 0).unwrap();
+        assert_eq!(
+            limiter.acquire_at(now, today, noon),
+            Slot::Wait(Duration::from_secs(12 * 60 * 60)),
+        );
+    }
+
+    #[test]
+    fn rate_limited_client() {
+        let limit = RateLimit::builder()
+            .monthly_budget(2)
+            .fail_fast(true)
+            .build()
+            .unwrap();
+        let client = RateLimited::new(eod_client(), limit);
+
+        let endpoint = Eod::builder().build().unwrap();
+        api::ignore(endpoint.clone()).query(&client).unwrap();
+        api::ignore(endpoint.clone()).query(&client).unwrap();
+        assert_eq!(client.used_this_month(), 2);
+
+        let err = api::ignore(endpoint).query(&client).unwrap_err();
+        if let ApiError::QuotaExhausted { budget } = err {
+            assert_eq!(budget, 2);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+    }
+
+    #[tokio::test]
+    async fn rate_limited_client_async() {
+        let limit = RateLimit::builder()
+            .per_second(100)
+            .monthly_budget(1)
+            .fail_fast(true)
+            .build()
+            .unwrap();
+        let client = RateLimited::new(eod_client(), limit);
+
+        let endpoint = Eod::builder().build().unwrap();
+        api::ignore(endpoint.clone())
+            .query_async(&client)
+            .await
+            .unwrap();
+
+        let err = api::ignore(endpoint)
+            .query_async(&client)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::QuotaExhausted { budget: 1 }));
+    }
+
+    #[test]
+    fn rate_limited_retries_count_every_attempt() {
+        let endpoint = ExpectedUrl::builder()
+            .endpoint("eod")
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .build()
+            .unwrap();
+        let limit = RateLimit::builder()
+            .monthly_budget(2)
+            .fail_fast(true)
+            .build()
+            .unwrap();
+        let policy = RetryPolicy::builder()
+            .max_attempts(3)
+            .initial_delay(Duration::from_millis(1))
+            .jitter(Jitter::None)
+            .build()
+            .unwrap();
+        let client = Retrying::new(
+            RateLimited::new(SingleTestClient::new_raw(endpoint, ""), limit),
+            policy,
+        );
+
+        // The third attempt is refused by the limiter instead of being sent.
+        let endpoint = Eod::builder().build().unwrap();
+        let err = api::ignore(endpoint).query(&client).unwrap_err();
+        assert!(matches!(err, ApiError::QuotaExhausted { budget: 2 }));
+        assert_eq!(client.inner().used_this_month(), 2);
+    }
+}
@@ -0,0 +1,708 @@
+//! Retrying of failed requests.
+//!
+//! Marketstack regularly answers with `429 Too Many Requests` or a transient `5xx` error.
+//! A [`RetryPolicy`] may be given to [`MarketstackBuilder`](crate::MarketstackBuilder) so that
+//! such requests are sent again after an exponentially growing delay.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use marketstack::{Jitter, Marketstack, RetryPolicy};
+//!
+//! let policy = RetryPolicy::builder()
+//!     .max_attempts(5)
+//!     .initial_delay(Duration::from_secs(1))
+//!     .jitter(Jitter::Full)
+//!     .build()
+//!     .unwrap();
+//!
+//! let client = Marketstack::builder("api.marketstack.com", "private-token")
+//!     .retry(policy)
+//!     .build()
+//!     .unwrap();
+//! ```
+//!
+//! Other clients, such as a [`RateLimited`](crate::RateLimited) one, may be wrapped with
+//! [`Retrying`] instead.
+
+use std::collections::hash_map::RandomState;
+use std::error::Error;
+use std::fmt::{self, Debug};
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use http::request::Builder as RequestBuilder;
+use http::{header, Request, Response, StatusCode};
+use url::Url;
+
+use crate::api::{ApiError, AsyncClient, Client, RestClient};
+use crate::auth::Auth;
+use crate::ApiVersion;
+
+/// The reason an attempt may be retried, as given to a retry classifier.
+#[derive(Debug, Clone, Copy)]
+pub enum RetryCause<'a> {
+    /// Marketstack responded with an unsuccessful status code.
+    Status(StatusCode),
+    /// The request failed before a response was received.
+    ///
+    /// For [`Marketstack`](crate::Marketstack) this is an `ApiError<RestError>`.
+    Error(&'a (dyn Error + 'static)),
+}
+
+/// A function deciding whether an attempt should be retried.
+pub type RetryClassifier = Arc<dyn Fn(RetryCause<'_>) -> bool + Send + Sync>;
+
+/// How to randomise the delay between attempts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Jitter {
+    /// Always wait for the computed delay.
+    None,
+    /// Wait anywhere between zero and the computed delay.
+    Full,
+    /// Wait between half of the computed delay and the full delay.
+    #[default]
+    Equal,
+}
+
+impl Jitter {
+    fn apply(self, delay: Duration) -> Duration {
+        match self {
+            Jitter::None => delay,
+            Jitter::Full => delay.mul_f64(random_fraction()),
+            Jitter::Equal => {
+                let half = delay / 2;
+                half + half.mul_f64(random_fraction())
+            }
+        }
+    }
+}
+
+/// A random number in `[0, 1)`.
+///
+/// `RandomState` is seeded randomly, which is plenty for spreading retries apart.
+fn random_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// The default classifier: rate limits, server errors, timeouts and connection failures.
+pub fn is_transient(cause: RetryCause<'_>) -> bool {
+    match cause {
+        RetryCause::Status(status) => {
+            status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+        }
+        RetryCause::Error(err) => {
+            let mut source = Some(err);
+            while let Some(err) = source {
+                if let Some(err) = err.downcast_ref::<reqwest::Error>() {
+                    return err.is_timeout() || err.is_connect();
+                }
+                source = err.source();
+            }
+            false
+        }
+    }
+}
+
+/// A policy for retrying failed requests with exponential backoff.
+#[derive(Builder, Clone)]
+#[builder(build_fn(validate = "Self::validate"))]
+pub struct RetryPolicy {
+    /// The total number of attempts, including the first one.
+    #[builder(default = "3")]
+    max_attempts: u32,
+    /// The delay before the first retry.
+    #[builder(default = "Duration::from_millis(500)")]
+    initial_delay: Duration,
+    /// The upper bound of any delay, including one asked for by `Retry-After`.
+    #[builder(default = "Duration::from_secs(30)")]
+    max_delay: Duration,
+    /// The factor the delay grows by after every retry.
+    #[builder(default = "2.0")]
+    multiplier: f64,
+    /// How to randomise the delay.
+    #[builder(default)]
+    jitter: Jitter,
+    /// Whether to wait for as long as a `Retry-After` header asks instead, up to `max_delay`.
+    #[builder(default = "true")]
+    respect_retry_after: bool,
+    /// Decides which failures are worth retrying.
+    #[builder(setter(custom), default = "Arc::new(is_transient)")]
+    classifier: RetryClassifier,
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_delay", &self.initial_delay)
+            .field("max_delay", &self.max_delay)
+            .field("multiplier", &self.multiplier)
+            .field("jitter", &self.jitter)
+            .field("respect_retry_after", &self.respect_retry_after)
+            .finish()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicyBuilder::default().build().unwrap()
+    }
+}
+
+impl RetryPolicyBuilder {
+    /// Decide which failures are retried.
+    ///
+    /// Defaults to [`is_transient`].
+    pub fn classifier<F>(&mut self, classifier: F) -> &mut Self
+    where
+        F: Fn(RetryCause<'_>) -> bool + Send + Sync + 'static,
+    {
+        self.classifier = Some(Arc::new(classifier));
+        self
+    }
+
+    /// Check that `RetryPolicy` contains sensible values.
+    fn validate(&self) -> Result<(), String> {
+        if self.max_attempts == Some(0) {
+            Err("`max_attempts` must be at least 1".into())
+        } else if self.multiplier.is_some_and(|m| m.is_nan() || m < 1.0) {
+            Err("`multiplier` must be at least 1.0".into())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a builder for the policy.
+    pub fn builder() -> RetryPolicyBuilder {
+        RetryPolicyBuilder::default()
+    }
+
+    /// The delay before the given retry, ignoring jitter.
+    ///
+    /// The first retry is `1`.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = self.multiplier.powi(retry.saturating_sub(1) as i32);
+        let delay = self.initial_delay.as_secs_f64() * factor;
+        if delay.is_finite() && delay < self.max_delay.as_secs_f64() {
+            Duration::from_secs_f64(delay)
+        } else {
+            self.max_delay
+        }
+    }
+
+    /// How long to wait before the next attempt, or `None` if the result should be returned.
+    fn next_delay<E>(
+        &self,
+        attempt: u32,
+        rsp: &Result<Response<Bytes>, ApiError<E>>,
+    ) -> Option<Duration>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        let cause = match rsp {
+            Ok(rsp) if rsp.status().is_success() => return None,
+            Ok(rsp) => RetryCause::Status(rsp.status()),
+            Err(err) => RetryCause::Error(err),
+        };
+        if !(self.classifier)(cause) {
+            return None;
+        }
+
+        let retry_after = rsp
+            .as_ref()
+            .ok()
+            .filter(|_| self.respect_retry_after)
+            .and_then(retry_after)
+            .map(|delay| delay.min(self.max_delay));
+        Some(retry_after.unwrap_or_else(|| self.jitter.apply(self.backoff(attempt))))
+    }
+
+    /// Send a request, retrying it according to the policy.
+    pub(crate) fn send<E, F>(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+        mut send: F,
+    ) -> Result<Response<Bytes>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+        F: FnMut(RequestBuilder, Vec<u8>) -> Result<Response<Bytes>, ApiError<E>>,
+    {
+        let mut request = request;
+        let mut attempt = 1;
+        loop {
+            let next = try_clone(&request);
+            let rsp = send(request, body.clone());
+            match (next, self.next_delay(attempt, &rsp)) {
+                (Some(next), Some(delay)) => {
+                    thread::sleep(delay);
+                    request = next;
+                    attempt += 1;
+                }
+                _ => return rsp,
+            }
+        }
+    }
+
+    /// Send a request asynchronously, retrying it according to the policy.
+    pub(crate) async fn send_async<E, F, Fut>(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+        mut send: F,
+    ) -> Result<Response<Bytes>, ApiError<E>>
+    where
+        E: Error + Send + Sync + 'static,
+        F: FnMut(RequestBuilder, Vec<u8>) -> Fut,
+        Fut: std::future::Future<Output = Result<Response<Bytes>, ApiError<E>>>,
+    {
+        let mut request = request;
+        let mut attempt = 1;
+        loop {
+            let next = try_clone(&request);
+            let rsp = send(request, body.clone()).await;
+            match (next, self.next_delay(attempt, &rsp)) {
+                (Some(next), Some(delay)) => {
+                    tokio::time::sleep(delay).await;
+                    request = next;
+                    attempt += 1;
+                }
+                _ => return rsp,
+            }
+        }
+    }
+}
+
+/// A client wrapper which retries failed requests according to a [`RetryPolicy`].
+///
+/// This is the same as [`MarketstackBuilder::retry`](crate::MarketstackBuilder::retry), but
+/// works for any client. Wrapping a [`RateLimited`](crate::RateLimited) client makes every
+/// attempt count against its quotas.
+#[derive(Debug, Clone)]
+pub struct Retrying<C> {
+    client: C,
+    policy: RetryPolicy,
+}
+
+impl<C> Retrying<C> {
+    /// Wrap a client with a retry policy.
+    pub fn new(client: C, policy: RetryPolicy) -> Self {
+        Self { client, policy }
+    }
+
+    /// The wrapped client.
+    pub fn inner(&self) -> &C {
+        &self.client
+    }
+}
+
+impl<C> RestClient for Retrying<C>
+where
+    C: RestClient,
+{
+    type Error = C::Error;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.client.rest_endpoint(endpoint)
+    }
+
+    fn versioned_rest_endpoint(
+        &self,
+        version: ApiVersion,
+        endpoint: &str,
+    ) -> Result<Url, ApiError<Self::Error>> {
+        self.client.versioned_rest_endpoint(version, endpoint)
+    }
+
+    fn get_auth(&self) -> Option<Auth> {
+        self.client.get_auth()
+    }
+}
+
+impl<C> Client for Retrying<C>
+where
+    C: Client,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.policy.send(request, body, |request, body| {
+            self.client.rest(request, body)
+        })
+    }
+}
+
+#[async_trait]
+impl<C> AsyncClient for Retrying<C>
+where
+    C: AsyncClient + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.policy
+            .send_async(request, body, |request, body| {
+                self.client.rest_async(request, body)
+            })
+            .await
+    }
+}
+
+/// Copy a request builder so that it may be sent again.
+///
+/// Returns `None` if the builder holds an error, as there is nothing worth retrying.
+fn try_clone(request: &RequestBuilder) -> Option<RequestBuilder> {
+    let mut builder = Request::builder()
+        .method(request.method_ref()?.clone())
+        .uri(request.uri_ref()?.clone())
+        .version(*request.version_ref()?);
+    for (key, value) in request.headers_ref()? {
+        builder = builder.header(key, value.clone());
+    }
+    Some(builder)
+}
+
+/// Parse the `Retry-After` header, given either in seconds or as an HTTP date.
+fn retry_after(rsp: &Response<Bytes>) -> Option<Duration> {
+    let value = rsp
+        .headers()
+        .get(header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        date.with_timezone(&Utc)
+            .signed_duration_since(Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    use bytes::Bytes;
+    use http::request::Builder as RequestBuilder;
+    use http::{header, Method, Request, Response, StatusCode};
+    use thiserror::Error;
+
+    use crate::api::ApiError;
+    use crate::retry::{is_transient, Jitter, RetryCause, RetryPolicy};
+
+    #[derive(Debug, Error)]
+    #[error("scripted failure")]
+    struct ScriptedError;
+
+    type Scripted = Result<Response<Bytes>, ApiError<ScriptedError>>;
+
+    fn status(status: StatusCode) -> Scripted {
+        Ok(Response::builder()
+            .status(status)
+            .body(Bytes::new())
+            .unwrap())
+    }
+
+    fn failure() -> Scripted {
+        Err(ApiError::client(ScriptedError))
+    }
+
+    /// Replay a scripted sequence of results, recording the requests made.
+    struct Script {
+        results: Mutex<VecDeque<Scripted>>,
+        requests: Mutex<Vec<(Method, String, Vec<u8>)>>,
+    }
+
+    impl Script {
+        fn new<I>(results: I) -> Self
+        where
+            I: IntoIterator<Item = Scripted>,
+        {
+            Self {
+                results: Mutex::new(results.into_iter().collect()),
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+
+        fn send(&self, request: RequestBuilder, body: Vec<u8>) -> Scripted {
+            let request = request.body(body).unwrap();
+            self.requests.lock().unwrap().push((
+                request.method().clone(),
+                request.uri().to_string(),
+                request.body().clone(),
+            ));
+            self.results
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("script exhausted")
+        }
+
+        fn attempts(&self) -> usize {
+            self.requests.lock().unwrap().len()
+        }
+    }
+
+    fn request() -> RequestBuilder {
+        Request::builder()
+            .method(Method::GET)
+            .uri("https://marketstack.host.invalid/v1/eod?access_key=123")
+    }
+
+    fn policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::builder()
+            .max_attempts(max_attempts)
+            .initial_delay(Duration::from_millis(1))
+            .jitter(Jitter::None)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn retry_defaults_are_sufficient() {
+        RetryPolicy::builder().build().unwrap();
+    }
+
+    #[test]
+    fn retry_zero_attempts() {
+        let err = RetryPolicy::builder().max_attempts(0).build().unwrap_err();
+        assert!(err.to_string().contains("max_attempts"));
+    }
+
+    #[test]
+    fn retry_shrinking_multiplier() {
+        let err = RetryPolicy::builder().multiplier(0.5).build().unwrap_err();
+        assert!(err.to_string().contains("multiplier"));
+    }
+
+    #[test]
+    fn retry_backoff_curve() {
+        let policy = RetryPolicy::builder()
+            .initial_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(500))
+            .build()
+            .unwrap();
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(100), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn retry_jitter_bounds() {
+        let delay = Duration::from_millis(100);
+        for _ in 0..100 {
+            assert!(Jitter::Full.apply(delay) <= delay);
+            let equal = Jitter::Equal.apply(delay);
+            assert!(equal >= delay / 2 && equal <= delay);
+        }
+        assert_eq!(Jitter::None.apply(delay), delay);
+    }
+
+    #[test]
+    fn retry_transient_statuses() {
+        assert!(is_transient(RetryCause::Status(
+            StatusCode::TOO_MANY_REQUESTS
+        )));
+        assert!(is_transient(RetryCause::Status(StatusCode::BAD_GATEWAY)));
+        assert!(!is_transient(RetryCause::Status(StatusCode::UNAUTHORIZED)));
+        assert!(!is_transient(RetryCause::Error(&ScriptedError)));
+    }
+
+    #[test]
+    fn retry_until_success() {
+        let script = Script::new([
+            status(StatusCode::TOO_MANY_REQUESTS),
+            status(StatusCode::SERVICE_UNAVAILABLE),
+            status(StatusCode::OK),
+        ]);
+
+        let rsp = policy(5)
+            .send(request(), b"body".to_vec(), |req, body| {
+                script.send(req, body)
+            })
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+        assert_eq!(script.attempts(), 3);
+
+        let requests = script.requests.lock().unwrap();
+        assert!(requests.iter().all(|(method, uri, body)| {
+            *method == Method::GET
+                && uri == "https://marketstack.host.invalid/v1/eod?access_key=123"
+                && body == b"body"
+        }));
+    }
+
+    #[test]
+    fn retry_gives_up() {
+        let script = Script::new([
+            status(StatusCode::INTERNAL_SERVER_ERROR),
+            status(StatusCode::INTERNAL_SERVER_ERROR),
+            status(StatusCode::INTERNAL_SERVER_ERROR),
+        ]);
+
+        let rsp = policy(3)
+            .send(request(), Vec::new(), |req, body| script.send(req, body))
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(script.attempts(), 3);
+    }
+
+    #[test]
+    fn retry_not_on_client_error() {
+        let script = Script::new([status(StatusCode::UNAUTHORIZED)]);
+
+        let rsp = policy(3)
+            .send(request(), Vec::new(), |req, body| script.send(req, body))
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(script.attempts(), 1);
+    }
+
+    #[test]
+    fn retry_custom_classifier() {
+        let script = Script::new([failure(), failure(), status(StatusCode::OK)]);
+        let policy = RetryPolicy::builder()
+            .max_attempts(3)
+            .initial_delay(Duration::ZERO)
+            .classifier(|cause| matches!(cause, RetryCause::Error(_)))
+            .build()
+            .unwrap();
+
+        let rsp = policy
+            .send(request(), Vec::new(), |req, body| script.send(req, body))
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+        assert_eq!(script.attempts(), 3);
+    }
+
+    #[test]
+    fn retry_not_on_unclassified_error() {
+        let script = Script::new([failure()]);
+
+        let err = policy(3)
+            .send(request(), Vec::new(), |req, body| script.send(req, body))
+            .unwrap_err();
+        assert!(matches!(err, ApiError::Client { .. }));
+        assert_eq!(script.attempts(), 1);
+    }
+
+    #[test]
+    fn retry_after_seconds() {
+        let policy = policy(2);
+        let rsp: Scripted = Ok(Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header(header::RETRY_AFTER, "7")
+            .body(Bytes::new())
+            .unwrap());
+
+        assert_eq!(policy.next_delay(1, &rsp), Some(Duration::from_secs(7)));
+        assert_eq!(policy.next_delay(2, &rsp), None);
+    }
+
+    #[test]
+    fn retry_after_capped() {
+        let policy = RetryPolicy::builder()
+            .max_delay(Duration::from_secs(10))
+            .build()
+            .unwrap();
+        let rsp: Scripted = Ok(Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header(header::RETRY_AFTER, "86400")
+            .body(Bytes::new())
+            .unwrap());
+        assert_eq!(policy.next_delay(1, &rsp), Some(Duration::from_secs(10)));
+
+        let rsp: Scripted = Ok(Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .header(header::RETRY_AFTER, "Fri, 31 Dec 9999 23:59:59 GMT")
+            .body(Bytes::new())
+            .unwrap());
+        assert_eq!(policy.next_delay(1, &rsp), Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn retry_after_date_in_past() {
+        let policy = policy(2);
+        let rsp: Scripted = Ok(Response::builder()
+            .status(StatusCode::SERVICE_UNAVAILABLE)
+            .header(header::RETRY_AFTER, "Wed, 21 Oct 2015 07:28:00 GMT")
+            .body(Bytes::new())
+            .unwrap());
+
+        assert_eq!(policy.next_delay(1, &rsp), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn retry_after_ignored() {
+        let policy = RetryPolicy::builder()
+            .initial_delay(Duration::from_millis(3))
+            .jitter(Jitter::None)
+            .respect_retry_after(false)
+            .build()
+            .unwrap();
+        let rsp: Scripted = Ok(Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .header(header::RETRY_AFTER, "7")
+            .body(Bytes::new())
+            .unwrap());
+
+        assert_eq!(policy.next_delay(1, &rsp), Some(Duration::from_millis(3)));
+    }
+
+    #[tokio::test]
+    async fn retry_until_success_async() {
+        let script = Script::new([
+            failure(),
+            status(StatusCode::BAD_GATEWAY),
+            status(StatusCode::OK),
+        ]);
+        let policy = RetryPolicy::builder()
+            .initial_delay(Duration::from_millis(1))
+            .classifier(|cause| match cause {
+                RetryCause::Error(_) => true,
+                cause => is_transient(cause),
+            })
+            .build()
+            .unwrap();
+
+        let rsp = policy
+            .send_async(request(), Vec::new(), |req, body| {
+                let rsp = script.send(req, body);
+                async move { rsp }
+            })
+            .await
+            .unwrap();
+        assert_eq!(rsp.status(), StatusCode::OK);
+        assert_eq!(script.attempts(), 3);
+    }
+}
@@ -0,0 +1,18 @@
+//! Mock clients for testing code which uses the Marketstack API.
+//!
+//! Available with the `testing` feature. The clients resolve endpoints against
+//! `https://marketstack.host.invalid/v1/`, or the version an endpoint asks for, authenticate with the access key `123` and panic on
+//! requests they do not expect.
+
+mod client;
+mod mock;
+
+pub use self::client::ExpectedUrl;
+pub use self::client::ExpectedUrlBuilder;
+pub use self::client::ExpectedUrlBuilderError;
+pub use self::client::PagedTestClient;
+pub use self::client::SingleTestClient;
+pub use self::client::TestClientError;
+
+pub use self::mock::MockClient;
+pub use self::mock::MockReply;
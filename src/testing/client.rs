@@ -0,0 +1,422 @@
+use std::borrow::Cow;
+use std::cmp;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use derive_builder::Builder;
+use http::request::Builder as RequestBuilder;
+use http::{header, Method, Response, StatusCode};
+use serde::ser::Serialize;
+use thiserror::Error;
+use url::Url;
+
+use crate::api::{ApiError, AsyncClient, Client, RestClient};
+use crate::auth::Auth;
+use crate::ApiVersion;
+
+/// The base URL of the test clients, without the API version.
+const CLIENT_STUB: &str = "https://marketstack.host.invalid";
+/// The access key of the test clients.
+pub(crate) const TOKEN: &str = "123";
+
+/// The URL of an endpoint of a version of the API on the test clients.
+pub(crate) fn client_url(version: ApiVersion, endpoint: &str) -> Result<Url, url::ParseError> {
+    Url::parse(&format!(
+        "{}/{}/{}",
+        CLIENT_STUB,
+        version.as_str(),
+        endpoint
+    ))
+}
+
+/// A request expected by a test client, along with the status and headers of its response.
+///
+/// Requests must carry `access_key=123` unless `access_key` is listed in the query.
+#[derive(Debug, Clone, Builder)]
+pub struct ExpectedUrl {
+    /// The method of the request.
+    #[builder(default = "Method::GET")]
+    pub method: Method,
+    /// The endpoint of the request, relative to the version of the API.
+    pub endpoint: &'static str,
+    /// The version of the API of the request.
+    #[builder(default)]
+    pub api_version: ApiVersion,
+    /// The query parameters of the request, in any order.
+    #[builder(default)]
+    pub query: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    /// The content type of the request body.
+    #[builder(setter(strip_option, into), default)]
+    pub content_type: Option<String>,
+    /// The body of the request.
+    #[builder(default)]
+    pub body: Vec<u8>,
+    /// The status of the response.
+    #[builder(default = "StatusCode::OK")]
+    pub status: StatusCode,
+    /// The headers of the response.
+    #[builder(default)]
+    pub response_headers: Vec<(&'static str, &'static str)>,
+
+    /// Whether `limit` and `offset` parameters are ignored.
+    #[builder(default = "false")]
+    pub paginated: bool,
+}
+
+impl ExpectedUrlBuilder {
+    /// Add query parameters to expect.
+    pub fn add_query_params(&mut self, pairs: &[(&'static str, &'static str)]) -> &mut Self {
+        self.query
+            .get_or_insert_with(Vec::new)
+            .extend(pairs.iter().cloned().map(|(k, v)| (k.into(), v.into())));
+        self
+    }
+}
+
+impl ExpectedUrl {
+    /// Create a builder for an expected request.
+    pub fn builder() -> ExpectedUrlBuilder {
+        ExpectedUrlBuilder::default()
+    }
+
+    /// Describe how a request differs from the expected one, if it does.
+    pub(crate) fn verify(&self, request: &RequestBuilder, body: &[u8]) -> Result<(), String> {
+        let method = request.method_ref().unwrap();
+        let url = Url::parse(&format!("{}", request.uri_ref().unwrap())).unwrap();
+
+        self.verify_url(method, &url)?;
+
+        if body != self.body.as_slice() {
+            return Err(format!(
+                "\nbody is not the same:\nactual  : {}\nexpected: {}\n",
+                String::from_utf8_lossy(body),
+                String::from_utf8_lossy(&self.body),
+            ));
+        }
+
+        let headers = request.headers_ref().unwrap();
+        let content_type = headers
+            .get_all(header::CONTENT_TYPE)
+            .iter()
+            .map(|value| value.to_str().unwrap())
+            .collect::<Vec<_>>();
+        let expected_content_type = self.content_type.iter().collect::<Vec<_>>();
+        if content_type != expected_content_type {
+            return Err(format!(
+                "content type {:?} is not the expected {:?}",
+                content_type, expected_content_type,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Panic if a request differs from the expected one.
+    pub(crate) fn check(&self, request: &RequestBuilder, body: &[u8]) {
+        if let Err(mismatch) = self.verify(request, body) {
+            panic!("{}", mismatch);
+        }
+    }
+
+    fn verify_url(&self, method: &Method, url: &Url) -> Result<(), String> {
+        fn expect<T>(what: &str, actual: T, expected: T) -> Result<(), String>
+        where
+            T: PartialEq + std::fmt::Debug,
+        {
+            if actual == expected {
+                Ok(())
+            } else {
+                Err(format!(
+                    "unexpected {}: {:?} (expected {:?})",
+                    what, actual, expected,
+                ))
+            }
+        }
+
+        // Test that the method is as expected.
+        expect("method", method, &self.method)?;
+
+        // Ensure that the URL was not tampered with in the meantime.
+        expect("scheme", url.scheme(), "https")?;
+        expect("username", url.username(), "")?;
+        expect("password", url.password(), None)?;
+        expect("host", url.host_str(), Some("marketstack.host.invalid"))?;
+        expect("port", url.port(), None)?;
+        expect("path", url.path(), &self.path())?;
+        let implicit_auth = !self.query.iter().any(|(key, _)| key == "access_key");
+        let mut authenticated = false;
+        let mut count = 0;
+        for (ref key, ref value) in url.query_pairs() {
+            if self.paginated && Self::is_pagination_key(key) {
+                continue;
+            }
+
+            // Every request carries the token of the test client.
+            if implicit_auth && key == "access_key" {
+                expect("access key", value.as_ref(), TOKEN)?;
+                authenticated = true;
+                continue;
+            }
+
+            let found = self.query.iter().any(|(expected_key, expected_value)| {
+                key == expected_key && value == expected_value
+            });
+
+            if !found {
+                return Err(format!("unexpected query parameter `{}={}`", key, value));
+            }
+            count += 1;
+        }
+        expect("number of query parameters", count, self.query.len())?;
+        if implicit_auth && !authenticated {
+            return Err("missing `access_key` parameter".into());
+        }
+        expect("fragment", url.fragment(), None)
+    }
+
+    /// The path of the endpoint, with any `..` segments resolved.
+    pub(crate) fn path(&self) -> String {
+        let url = client_url(self.api_version, self.endpoint).unwrap();
+        url.path().into()
+    }
+
+    fn is_pagination_key(key: &str) -> bool {
+        key == "pagination" || key == "offset" || key == "limit"
+    }
+}
+
+/// A canned response of a test client.
+#[derive(Debug, Clone)]
+pub(crate) struct MockResponse {
+    pub(crate) status: StatusCode,
+    pub(crate) headers: Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    pub(crate) data: Vec<u8>,
+}
+
+impl MockResponse {
+    pub(crate) fn response(&self) -> Response<Bytes> {
+        let mut rsp = Response::builder().status(self.status);
+        for (key, value) in &self.headers {
+            rsp = rsp.header(key.as_ref(), value.as_ref());
+        }
+        rsp.body(self.data.clone().into()).unwrap()
+    }
+}
+
+/// A client which expects a single request, which may be sent any number of times.
+pub struct SingleTestClient {
+    expected: ExpectedUrl,
+    response: MockResponse,
+    auth: Auth,
+}
+
+impl SingleTestClient {
+    /// Respond to the expected request with the given body.
+    pub fn new_raw<T>(expected: ExpectedUrl, data: T) -> Self
+    where
+        T: Into<Vec<u8>>,
+    {
+        let response = MockResponse {
+            status: expected.status,
+            headers: expected
+                .response_headers
+                .iter()
+                .map(|&(key, value)| (key.into(), value.into()))
+                .collect(),
+            data: data.into(),
+        };
+        let auth = Auth::Token(TOKEN.into());
+
+        Self {
+            expected,
+            response,
+            auth,
+        }
+    }
+
+    /// Respond to the expected request with the given data as JSON.
+    pub fn new_json<T>(expected: ExpectedUrl, data: &T) -> Self
+    where
+        T: Serialize,
+    {
+        let data = serde_json::to_vec(data).unwrap();
+        Self::new_raw(expected, data)
+    }
+}
+
+/// Errors of the test clients.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum TestClientError {
+    /// An error scripted into a [`MockClient`](crate::testing::MockClient).
+    #[error("scripted client error: {}", message)]
+    Scripted {
+        /// The message of the error.
+        message: String,
+    },
+}
+
+impl RestClient for SingleTestClient {
+    type Error = TestClientError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.versioned_rest_endpoint(ApiVersion::V1, endpoint)
+    }
+
+    fn versioned_rest_endpoint(
+        &self,
+        version: ApiVersion,
+        endpoint: &str,
+    ) -> Result<Url, ApiError<Self::Error>> {
+        Ok(client_url(version, endpoint)?)
+    }
+
+    fn get_auth(&self) -> Option<Auth> {
+        Some(self.auth.clone())
+    }
+}
+
+impl Client for SingleTestClient {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.expected.check(&request, &body);
+
+        Ok(self.response.response())
+    }
+}
+
+#[async_trait]
+impl AsyncClient for SingleTestClient {
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<<Self as RestClient>::Error>> {
+        <Self as Client>::rest(self, request, body)
+    }
+}
+
+/// A client which serves pages of the given data for the expected request.
+pub struct PagedTestClient<T> {
+    expected: ExpectedUrl,
+    data: Vec<T>,
+    auth: Auth,
+    requests: AtomicUsize,
+}
+
+// Marketstack defaults to 100 results per page when no `limit` is given.
+const DEFAULT_PAGE_SIZE: usize = 100;
+
+impl<T> PagedTestClient<T> {
+    /// Serve pages of the given data.
+    pub fn new_raw<I>(expected: ExpectedUrl, data: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let data = data.into_iter().collect();
+        let auth = Auth::Token(TOKEN.into());
+
+        Self {
+            expected,
+            data,
+            auth,
+            requests: AtomicUsize::new(0),
+        }
+    }
+
+    /// The number of requests the client has served.
+    pub fn request_count(&self) -> usize {
+        self.requests.load(Ordering::SeqCst)
+    }
+}
+
+impl<T> RestClient for PagedTestClient<T> {
+    type Error = TestClientError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.versioned_rest_endpoint(ApiVersion::V1, endpoint)
+    }
+
+    fn versioned_rest_endpoint(
+        &self,
+        version: ApiVersion,
+        endpoint: &str,
+    ) -> Result<Url, ApiError<Self::Error>> {
+        Ok(client_url(version, endpoint)?)
+    }
+
+    fn get_auth(&self) -> Option<Auth> {
+        Some(self.auth.clone())
+    }
+}
+
+impl<T> Client for PagedTestClient<T>
+where
+    T: Serialize,
+{
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        self.expected.check(&request, &body);
+        assert_eq!(*request.method_ref().unwrap(), Method::GET);
+
+        self.requests.fetch_add(1, Ordering::SeqCst);
+
+        let url = Url::parse(&format!("{}", request.uri_ref().unwrap())).unwrap();
+        let mut offset = 0;
+        let mut limit = DEFAULT_PAGE_SIZE;
+
+        for (ref key, ref value) in url.query_pairs() {
+            match key.as_ref() {
+                "offset" => {
+                    offset = value.parse().unwrap();
+                }
+                "limit" => {
+                    limit = value.parse().unwrap();
+                }
+                _ => (),
+            }
+        }
+
+        // Limit the range to the amount of data actually available.
+        let start = cmp::min(offset, self.data.len());
+        let end = cmp::min(offset + limit, self.data.len());
+        let data_page = &self.data[start..end];
+
+        let page = serde_json::json!({
+            "pagination": {
+                "limit": limit,
+                "offset": offset,
+                "count": data_page.len(),
+                "total": self.data.len(),
+            },
+            "data": data_page,
+        });
+
+        Ok(Response::builder()
+            .status(self.expected.status)
+            .body(serde_json::to_vec(&page).unwrap())
+            .unwrap()
+            .map(Into::into))
+    }
+}
+
+#[async_trait]
+impl<T> AsyncClient for PagedTestClient<T>
+where
+    T: Serialize + Send + Sync,
+{
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<<Self as RestClient>::Error>> {
+        <Self as Client>::rest(self, request, body)
+    }
+}
@@ -0,0 +1,486 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use http::request::Builder as RequestBuilder;
+use http::{Response, StatusCode};
+use serde::ser::Serialize;
+use url::Url;
+
+use crate::api::{ApiError, AsyncClient, Client, RestClient};
+use crate::auth::Auth;
+use crate::testing::client::{client_url, MockResponse, TOKEN};
+use crate::testing::{ExpectedUrl, TestClientError};
+use crate::ApiVersion;
+
+/// A scripted reply of a [`MockClient`].
+#[derive(Debug, Clone)]
+pub struct MockReply {
+    reply: Reply,
+}
+
+#[derive(Debug, Clone)]
+enum Reply {
+    Response(MockResponse),
+    Error(String),
+}
+
+impl MockReply {
+    /// Reply with the given body and a `200 OK` status.
+    pub fn raw<T>(data: T) -> Self
+    where
+        T: Into<Vec<u8>>,
+    {
+        Self {
+            reply: Reply::Response(MockResponse {
+                status: StatusCode::OK,
+                headers: Vec::new(),
+                data: data.into(),
+            }),
+        }
+    }
+
+    /// Reply with the given data as JSON and a `200 OK` status.
+    pub fn json<T>(data: &T) -> Self
+    where
+        T: Serialize,
+    {
+        Self::raw(serde_json::to_vec(data).unwrap())
+    }
+
+    /// Reply with an empty body and the given status.
+    pub fn status(status: StatusCode) -> Self {
+        Self::raw(Vec::new()).with_status(status)
+    }
+
+    /// Fail the request with a client error, as if Marketstack could not be reached.
+    pub fn error<M>(message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Self {
+            reply: Reply::Error(message.into()),
+        }
+    }
+
+    /// Set the status of the reply.
+    ///
+    /// Has no effect on client errors.
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        if let Reply::Response(rsp) = &mut self.reply {
+            rsp.status = status;
+        }
+        self
+    }
+
+    /// Add a header to the reply.
+    ///
+    /// Has no effect on client errors.
+    pub fn with_header<K, V>(mut self, key: K, value: V) -> Self
+    where
+        K: Into<Cow<'static, str>>,
+        V: Into<Cow<'static, str>>,
+    {
+        if let Reply::Response(rsp) = &mut self.reply {
+            rsp.headers.push((key.into(), value.into()));
+        }
+        self
+    }
+}
+
+#[derive(Debug)]
+struct Route {
+    expected: ExpectedUrl,
+    replies: VecDeque<MockReply>,
+}
+
+/// A client which expects a script of requests and replies to each of them in turn.
+///
+/// By default, requests must arrive in the order they were expected. Each expected request
+/// replies with its scripted replies in order, and is done once all of them were sent.
+///
+/// ```rust
+/// use http::StatusCode;
+/// use marketstack::api::currencies::Currencies;
+/// use marketstack::api::Query;
+/// use marketstack::testing::{ExpectedUrl, MockClient, MockReply};
+/// use marketstack::CurrenciesData;
+///
+/// let page = serde_json::json!({
+///     "pagination": {"limit": 100, "offset": 0, "count": 0, "total": 0},
+///     "data": [],
+/// });
+/// let client = MockClient::new();
+/// client.expect_sequence(
+///     ExpectedUrl::builder().endpoint("currencies").build().unwrap(),
+///     [
+///         MockReply::status(StatusCode::SERVICE_UNAVAILABLE),
+///         MockReply::json(&page),
+///     ],
+/// );
+///
+/// let endpoint = Currencies::builder().build().unwrap();
+/// let res: Result<CurrenciesData, _> = endpoint.query(&client);
+/// assert!(res.is_err());
+/// let res: Result<CurrenciesData, _> = endpoint.query(&client);
+/// assert!(res.is_ok());
+///
+/// client.assert_done();
+/// ```
+#[derive(Debug)]
+pub struct MockClient {
+    ordered: bool,
+    routes: Mutex<VecDeque<Route>>,
+    auth: Auth,
+}
+
+impl Default for MockClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockClient {
+    /// Create a client which expects requests in order.
+    pub fn new() -> Self {
+        Self {
+            ordered: true,
+            routes: Mutex::new(VecDeque::new()),
+            auth: Auth::Token(TOKEN.into()),
+        }
+    }
+
+    /// Create a client which accepts the expected requests in any order.
+    ///
+    /// Each request is matched against the expected requests in the order they were added.
+    pub fn unordered() -> Self {
+        Self {
+            ordered: false,
+            ..Self::new()
+        }
+    }
+
+    /// Expect a request and reply with the status and headers of the expectation.
+    pub fn expect<T>(&self, expected: ExpectedUrl, data: T) -> &Self
+    where
+        T: Into<Vec<u8>>,
+    {
+        let mut reply = MockReply::raw(data).with_status(expected.status);
+        for &(key, value) in &expected.response_headers {
+            reply = reply.with_header(key, value);
+        }
+        self.expect_sequence(expected, [reply])
+    }
+
+    /// Expect a request and reply with the given data as JSON.
+    pub fn expect_json<T>(&self, expected: ExpectedUrl, data: &T) -> &Self
+    where
+        T: Serialize,
+    {
+        self.expect(expected, serde_json::to_vec(data).unwrap())
+    }
+
+    /// Expect a request once for each of the given replies.
+    pub fn expect_sequence<I>(&self, expected: ExpectedUrl, replies: I) -> &Self
+    where
+        I: IntoIterator<Item = MockReply>,
+    {
+        let replies: VecDeque<_> = replies.into_iter().collect();
+        if !replies.is_empty() {
+            self.lock().push_back(Route { expected, replies });
+        }
+        self
+    }
+
+    /// Whether every expected request has been sent.
+    pub fn is_done(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Panic if an expected request has not been sent.
+    pub fn assert_done(&self) {
+        let routes = self.lock();
+        if let Some(route) = routes.front() {
+            panic!(
+                "{} expected request(s) were not sent, starting with {} `{}`",
+                routes
+                    .iter()
+                    .map(|route| route.replies.len())
+                    .sum::<usize>(),
+                route.expected.method,
+                route.expected.endpoint,
+            );
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<Route>> {
+        // A failed expectation panics while holding the lock; later checks should still work.
+        self.routes
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn reply(&self, request: &RequestBuilder, body: &[u8]) -> MockReply {
+        let mut routes = self.lock();
+
+        let index = if self.ordered {
+            let route = routes
+                .front()
+                .unwrap_or_else(|| panic!("unexpected request: {:?}", request.uri_ref().unwrap()));
+            if let Err(mismatch) = route.expected.verify(request, body) {
+                panic!("request does not match the next expectation: {}", mismatch);
+            }
+            0
+        } else {
+            routes
+                .iter()
+                .position(|route| route.expected.verify(request, body).is_ok())
+                .unwrap_or_else(|| {
+                    panic!(
+                        "no expectation matches request: {} {:?}",
+                        request.method_ref().unwrap(),
+                        request.uri_ref().unwrap(),
+                    )
+                })
+        };
+
+        let route = &mut routes[index];
+        let reply = route.replies.pop_front().unwrap();
+        if route.replies.is_empty() {
+            routes.remove(index);
+        }
+        reply
+    }
+}
+
+impl RestClient for MockClient {
+    type Error = TestClientError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<Url, ApiError<Self::Error>> {
+        self.versioned_rest_endpoint(ApiVersion::V1, endpoint)
+    }
+
+    fn versioned_rest_endpoint(
+        &self,
+        version: ApiVersion,
+        endpoint: &str,
+    ) -> Result<Url, ApiError<Self::Error>> {
+        Ok(client_url(version, endpoint)?)
+    }
+
+    fn get_auth(&self) -> Option<Auth> {
+        Some(self.auth.clone())
+    }
+}
+
+impl Client for MockClient {
+    fn rest(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<Self::Error>> {
+        match self.reply(&request, &body).reply {
+            Reply::Response(rsp) => Ok(rsp.response()),
+            Reply::Error(message) => Err(ApiError::client(TestClientError::Scripted { message })),
+        }
+    }
+}
+
+#[async_trait]
+impl AsyncClient for MockClient {
+    async fn rest_async(
+        &self,
+        request: RequestBuilder,
+        body: Vec<u8>,
+    ) -> Result<Response<Bytes>, ApiError<<Self as RestClient>::Error>> {
+        <Self as Client>::rest(self, request, body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use serde_json::json;
+
+    use crate::api::endpoint_prelude::*;
+    use crate::api::{self, ApiError, AsyncQuery, Query};
+    use crate::testing::{ExpectedUrl, MockClient, MockReply, TestClientError};
+
+    struct Dummy(&'static str);
+
+    impl Endpoint for Dummy {
+        fn method(&self) -> Method {
+            Method::GET
+        }
+
+        fn endpoint(&self) -> Cow<'static, str> {
+            self.0.into()
+        }
+    }
+
+    fn expected(endpoint: &'static str) -> ExpectedUrl {
+        ExpectedUrl::builder().endpoint(endpoint).build().unwrap()
+    }
+
+    #[test]
+    fn test_ordered() {
+        let client = MockClient::new();
+        client
+            .expect_json(expected("first"), &json!({"value": 1}))
+            .expect_json(expected("second"), &json!({"value": 2}));
+
+        let first: serde_json::Value = Dummy("first").query(&client).unwrap();
+        assert!(!client.is_done());
+        let second: serde_json::Value = Dummy("second").query(&client).unwrap();
+
+        assert_eq!(first["value"], 1);
+        assert_eq!(second["value"], 2);
+        client.assert_done();
+    }
+
+    #[test]
+    #[should_panic = "request does not match the next expectation"]
+    fn test_ordered_out_of_order() {
+        let client = MockClient::new();
+        client
+            .expect(expected("first"), "")
+            .expect(expected("second"), "");
+
+        api::ignore(Dummy("second")).query(&client).unwrap();
+    }
+
+    #[test]
+    fn test_unordered() {
+        let client = MockClient::unordered();
+        client
+            .expect_json(expected("first"), &json!({"value": 1}))
+            .expect_json(expected("second"), &json!({"value": 2}));
+
+        let second: serde_json::Value = Dummy("second").query(&client).unwrap();
+        let first: serde_json::Value = Dummy("first").query(&client).unwrap();
+
+        assert_eq!(first["value"], 1);
+        assert_eq!(second["value"], 2);
+        client.assert_done();
+    }
+
+    #[test]
+    #[should_panic = "no expectation matches request"]
+    fn test_unordered_unexpected() {
+        let client = MockClient::unordered();
+        client.expect(expected("first"), "");
+
+        api::ignore(Dummy("second")).query(&client).unwrap();
+    }
+
+    #[test]
+    #[should_panic = "unexpected request"]
+    fn test_exhausted() {
+        let client = MockClient::new();
+        client.expect(expected("first"), "");
+
+        api::ignore(Dummy("first")).query(&client).unwrap();
+        api::ignore(Dummy("first")).query(&client).unwrap();
+    }
+
+    #[test]
+    #[should_panic = "1 expected request(s) were not sent, starting with GET `second`"]
+    fn test_assert_done() {
+        let client = MockClient::new();
+        client
+            .expect(expected("first"), "")
+            .expect(expected("second"), "");
+
+        api::ignore(Dummy("first")).query(&client).unwrap();
+        client.assert_done();
+    }
+
+    #[test]
+    fn test_sequence() {
+        let client = MockClient::new();
+        client.expect_sequence(
+            expected("dummy"),
+            [
+                MockReply::error("connection reset"),
+                MockReply::json(&json!({"error": {"code": "rate_limit_reached"}}))
+                    .with_status(StatusCode::TOO_MANY_REQUESTS)
+                    .with_header("retry-after", "1"),
+                MockReply::status(StatusCode::INTERNAL_SERVER_ERROR),
+                MockReply::json(&json!({"value": 0})),
+            ],
+        );
+
+        let err = api::ignore(Dummy("dummy")).query(&client).unwrap_err();
+        if let ApiError::Client {
+            source: TestClientError::Scripted { message },
+        } = err
+        {
+            assert_eq!(message, "connection reset");
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        let err = api::ignore(Dummy("dummy")).query(&client).unwrap_err();
+        assert!(matches!(err, ApiError::MarketstackError { .. }), "{}", err);
+
+        let err = api::ignore(Dummy("dummy")).query(&client).unwrap_err();
+        if let ApiError::MarketstackService { status, .. } = err {
+            assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+        } else {
+            panic!("unexpected error: {}", err);
+        }
+
+        api::ignore(Dummy("dummy")).query(&client).unwrap();
+        client.assert_done();
+    }
+
+    #[test]
+    fn test_response_status_and_headers() {
+        let client = MockClient::new();
+        client
+            .expect(
+                ExpectedUrl::builder()
+                    .endpoint("dummy")
+                    .status(StatusCode::NOT_FOUND)
+                    .build()
+                    .unwrap(),
+                "",
+            )
+            .expect_sequence(
+                expected("dummy"),
+                [MockReply::json(&json!({"value": 0})).with_header("x-request-id", "abc123")],
+            );
+
+        api::raw(Dummy("dummy")).query(&client).unwrap_err();
+        let rsp: api::Response<serde_json::Value> =
+            api::with_meta(Dummy("dummy")).query(&client).unwrap();
+        assert_eq!(rsp.header("x-request-id"), Some("abc123"));
+        client.assert_done();
+    }
+
+    #[tokio::test]
+    async fn test_async() {
+        let client = MockClient::new();
+        client
+            .expect_sequence(
+                expected("dummy"),
+                [MockReply::error("timed out"), MockReply::raw("")],
+            )
+            .expect_json(expected("other"), &json!({"value": 1}));
+
+        api::ignore(Dummy("dummy"))
+            .query_async(&client)
+            .await
+            .unwrap_err();
+        api::ignore(Dummy("dummy"))
+            .query_async(&client)
+            .await
+            .unwrap();
+        let other: serde_json::Value = Dummy("other").query_async(&client).await.unwrap();
+
+        assert_eq!(other["value"], 1);
+        client.assert_done();
+    }
+}
@@ -3,6 +3,8 @@
 use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
+pub mod v2;
+
 /// Pagination Information returned by Marketstack API.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PaginationInfo {
@@ -16,6 +18,70 @@ pub struct PaginationInfo {
     pub total: u64,
 }
 
+/// Rust representation of a paginated JSON response from Marketstack.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Paginated<T> {
+    /// Corresponds to pagination entry from JSON response from marketstack.
+    pub pagination: PaginationInfo,
+    /// Corresponds to data entry from JSON response from marketstack.
+    pub data: Vec<T>,
+}
+
+impl<T> Paginated<T> {
+    /// Whether Marketstack has more results after this page.
+    pub fn has_more(&self) -> bool {
+        !self.data.is_empty()
+            && self.pagination.offset + (self.data.len() as u64) < self.pagination.total
+    }
+
+    /// The offset of the next page, if there is one.
+    pub fn next_offset(&self) -> Option<u64> {
+        if self.has_more() {
+            Some(self.pagination.offset + self.data.len() as u64)
+        } else {
+            None
+        }
+    }
+
+    /// The results on this page.
+    pub fn into_items(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Iterate over the results on this page.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// The number of results on this page.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether this page has no results.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+impl<T> IntoIterator for Paginated<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Paginated<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
+    }
+}
+
 /// Rust representation of single data item from Marketstack `eod` response.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct EodDataItem {
@@ -53,12 +119,48 @@ pub struct EodDataItem {
 }
 
 /// Rust representation of the JSON response from `eod` marketstack endpoint.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct EodData {
-    /// Corresponds to pagination entry from JSON response from marketstack.
-    pub pagination: PaginationInfo,
-    /// Corresponds to data entry from JSON response from marketstack.
-    pub data: Vec<EodDataItem>,
+pub type EodData = Paginated<EodDataItem>;
+
+/// A daily row of a price series, e.g. of a stock or an index.
+///
+/// Lets series of different kinds be processed together.
+pub trait EodRow {
+    /// Symbol of the stock or index.
+    fn symbol(&self) -> &str;
+    /// Day of the row.
+    fn day(&self) -> NaiveDate;
+    /// Closing price or level of the day.
+    fn close(&self) -> f64;
+
+    /// Change of the close since a previous row.
+    fn change_since(&self, previous: &dyn EodRow) -> f64 {
+        self.close() - previous.close()
+    }
+
+    /// Relative change of the close since a previous row, where `0.01` is 1%.
+    ///
+    /// Returns `None` if the previous close is zero, as there is no meaningful return then.
+    fn return_since(&self, previous: &dyn EodRow) -> Option<f64> {
+        if previous.close() == 0.0 {
+            None
+        } else {
+            Some(self.change_since(previous) / previous.close())
+        }
+    }
+}
+
+impl EodRow for EodDataItem {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    fn day(&self) -> NaiveDate {
+        self.date.date_naive()
+    }
+
+    fn close(&self) -> f64 {
+        self.close
+    }
 }
 
 /// Rust representation of single data item from Marketstack `splits` response.
@@ -73,13 +175,7 @@ pub struct SplitsDataItem {
 }
 
 /// Rust representation of the JSON response from `splits` marketstack endpoint.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct SplitsData {
-    /// Corresponds to pagination entry from JSON response from marketstack.
-    pub pagination: PaginationInfo,
-    /// Corresponds to data entry from JSON response from marketstack.
-    pub data: Vec<SplitsDataItem>,
-}
+pub type SplitsData = Paginated<SplitsDataItem>;
 
 /// Rust representation of single data item from Marketstack `dividends` response.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -93,13 +189,7 @@ pub struct DividendsDataItem {
 }
 
 /// Rust representation of the JSON response from `dividends` marketstack endpoint.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct DividendsData {
-    /// Corresponds to pagination entry from JSON response from marketstack.
-    pub pagination: PaginationInfo,
-    /// Corresponds to data entry from JSON response from marketstack.
-    pub data: Vec<DividendsDataItem>,
-}
+pub type DividendsData = Paginated<DividendsDataItem>;
 
 /// Rust representation of single data item from Marketstack `currencies` response.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -113,13 +203,7 @@ pub struct CurrenciesDataItem {
 }
 
 /// Rust representation of the JSON response from `currencies` marketstack endpoint.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct CurrenciesData {
-    /// Corresponds to pagination entry from JSON response from marketstack.
-    pub pagination: PaginationInfo,
-    /// Corresponds to data entry from JSON response from marketstack.
-    pub data: Vec<CurrenciesDataItem>,
-}
+pub type CurrenciesData = Paginated<CurrenciesDataItem>;
 
 /// Rust representation of single data item from Marketstack `timezones` response.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -133,13 +217,7 @@ pub struct TimezonesDataItem {
 }
 
 /// Rust representation of the JSON response from `timezones` marketstack endpoint.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct TimezonesData {
-    /// Corresponds to pagination entry from JSON response from marketstack.
-    pub pagination: PaginationInfo,
-    /// Corresponds to data entry from JSON response from marketstack.
-    pub data: Vec<TimezonesDataItem>,
-}
+pub type TimezonesData = Paginated<TimezonesDataItem>;
 
 /// Rust represenation of a stock exchange.
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -197,13 +275,7 @@ pub struct TickerEodDataInner {
 }
 
 /// Rust representation of the JSON response from `tickers` marketstack endpoint.
-#[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct TickersData {
-    /// Corresponds to pagination entry from JSON response from marketstack.
-    pub pagination: PaginationInfo,
-    /// Corresponds to data entry from JSON response from marketstack.
-    pub data: Vec<TickersDataItem>,
-}
+pub type TickersData = Paginated<TickersDataItem>;
 
 /// Rust representation of the JSON response from `tickers/[symbol]/eod` marketstack endpoint.
 /// Implementation seems slightly repetitive, but return types from Marketstack are slightly
@@ -216,6 +288,20 @@ pub struct TickersEodData {
     pub data: TickerEodDataInner,
 }
 
+/// Rust representation of the JSON response from `tickers/[symbol]` marketstack endpoint.
+pub type TickerData = TickersDataItem;
+
+/// Rust representation of the JSON response from `tickers/[symbol]/eod/latest` and
+/// `tickers/[symbol]/eod/[date]` marketstack endpoints, which return a single row.
+pub type TickersEodLatestData = EodDataItem;
+
+/// Rust representation of the JSON response from `tickers/[symbol]/splits` marketstack endpoint.
+pub type TickersSplitsData = Paginated<SplitsDataItem>;
+
+/// Rust representation of the JSON response from `tickers/[symbol]/dividends` marketstack
+/// endpoint.
+pub type TickersDividendsData = Paginated<DividendsDataItem>;
+
 /// Rust representation of a single data item from Marketstack `exchanges` endpoint.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ExchangesDataItem {
@@ -229,12 +315,53 @@ pub struct ExchangesDataItem {
 }
 
 /// Rust representation of the JSON response from `exchanges` marketstack endpoint.
+pub type ExchangesData = Paginated<ExchangesDataItem>;
+
+/// Rust representation of the JSON response from `exchanges/[mic]` marketstack endpoint.
+pub type ExchangeData = ExchangesDataItem;
+
+/// Rust representation of a single ticker from Marketstack `exchanges/[mic]/tickers` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExchangeTicker {
+    /// Name of the given stock ticker.
+    pub name: String,
+    /// Symbol of the given stock ticker.
+    pub symbol: String,
+    /// Whether intraday data is available for the stock ticker.
+    pub has_intraday: bool,
+    /// Whether eod data is available for the stock ticker.
+    pub has_eod: bool,
+}
+
+/// Rust representation of the data entry from Marketstack `exchanges/[mic]/tickers` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExchangesTickersDataInner {
+    /// Name of the stock exchange.
+    pub name: String,
+    /// Acronym of the stock exchange.
+    pub acronym: String,
+    /// MIC identification of the stock exchange.
+    pub mic: String,
+    /// Country of the stock exchange.
+    pub country: String,
+    /// 3-letter country code of the stock exchange - if available, else `None`.
+    #[serde(default)]
+    pub country_code: Option<String>,
+    /// City of the stock exchange.
+    pub city: String,
+    /// Website URL of the stock exchange.
+    pub website: String,
+    /// Tickers traded on the stock exchange.
+    pub tickers: Vec<ExchangeTicker>,
+}
+
+/// Rust representation of the JSON response from `exchanges/[mic]/tickers` marketstack endpoint.
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ExchangesData {
+pub struct ExchangesTickersData {
     /// Corresponds to pagination entry from JSON response from marketstack.
     pub pagination: PaginationInfo,
     /// Corresponds to data entry from JSON response from marketstack.
-    pub data: Vec<ExchangesDataItem>,
+    pub data: ExchangesTickersDataInner,
 }
 
 /// Rust representation of a single data item from Marketstack `exchanges/[mic]/eod`` response.
@@ -292,21 +419,127 @@ pub struct IntradayDataItem {
 }
 
 /// Rust representation of the JSON response from `intraday` marketstack endpoint.
+pub type IntradayData = Paginated<IntradayDataItem>;
+
+/// Rust representation of the data entry from Marketstack `tickers/[symbol]/intraday` response.
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct IntradayData {
+pub struct TickerIntradayDataInner {
+    /// Name of the given stock ticker.
+    pub name: String,
+    /// Symbol of the given stock ticker.
+    pub symbol: String,
+    /// Whether intraday data is available for the stock ticker.
+    pub has_intraday: bool,
+    /// Whether eod data is available for the stock ticker.
+    pub has_eod: bool,
+    /// Country ticker is traded in - if available, else `None`.
+    pub country: Option<String>,
+    /// Collection of intraday data for the ticker.
+    pub intraday: Vec<IntradayDataItem>,
+}
+
+/// Rust representation of the JSON response from `tickers/[symbol]/intraday` and
+/// `tickers/[symbol]/intraday/[date]` marketstack endpoints.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TickersIntradayData {
+    /// Corresponds to pagination entry from JSON response from marketstack.
+    pub pagination: PaginationInfo,
+    /// Corresponds to data entry from JSON response from marketstack.
+    pub data: TickerIntradayDataInner,
+}
+
+/// Rust representation of the JSON response from `tickers/[symbol]/intraday/latest` marketstack
+/// endpoint, which returns a single row.
+pub type TickersIntradayLatestData = IntradayDataItem;
+
+/// Rust representation of the data entry from Marketstack `exchanges/[mic]/intraday` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExchangesIntradayDataInner {
+    /// Name of the stock exchange.
+    pub name: String,
+    /// Acronym of the stock exchange.
+    pub acronym: String,
+    /// MIC identification of the stock exchange.
+    pub mic: String,
+    /// Country of the stock exchange.
+    pub country: String,
+    /// City of the stock exchange.
+    pub city: String,
+    /// Website URL of the stock exchange.
+    pub website: String,
+    /// Collection of intraday data for the exchange.
+    pub intraday: Vec<IntradayDataItem>,
+}
+
+/// Rust representation of the JSON response from `exchanges/[mic]/intraday` marketstack endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ExchangesIntradayData {
     /// Corresponds to pagination entry from JSON response from marketstack.
     pub pagination: PaginationInfo,
     /// Corresponds to data entry from JSON response from marketstack.
-    pub data: Vec<IntradayDataItem>,
+    pub data: ExchangesIntradayDataInner,
+}
+
+/// Rust representation of the JSON responses from the `tickers` family of endpoints.
+///
+/// The shape of the response depends on which nested endpoint is requested.
+/// [`TypedEndpoint::fetch`](crate::api::TypedEndpoint::fetch) picks the shape from the endpoint,
+/// while deserializing on its own picks the first variant which matches the data.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum TickersResponse {
+    /// Response from `tickers`.
+    List(TickersData),
+    /// Response from `tickers/[symbol]`.
+    Ticker(TickerData),
+    /// Response from `tickers/[symbol]/eod`.
+    Eod(TickersEodData),
+    /// Response from `tickers/[symbol]/eod/latest` and `tickers/[symbol]/eod/[date]`.
+    EodDay(TickersEodLatestData),
+    /// Response from `tickers/[symbol]/splits`.
+    Splits(TickersSplitsData),
+    /// Response from `tickers/[symbol]/dividends`.
+    Dividends(TickersDividendsData),
+    /// Response from `tickers/[symbol]/intraday` and `tickers/[symbol]/intraday/[date]`.
+    Intraday(TickersIntradayData),
+    /// Response from `tickers/[symbol]/intraday/latest`.
+    IntradayLatest(TickersIntradayLatestData),
+}
+
+/// Rust representation of the JSON responses from the `exchanges` family of endpoints.
+///
+/// The shape of the response depends on which nested endpoint is requested.
+/// [`TypedEndpoint::fetch`](crate::api::TypedEndpoint::fetch) picks the shape from the endpoint,
+/// while deserializing on its own picks the first variant which matches the data.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum ExchangesResponse {
+    /// Response from `exchanges`.
+    List(ExchangesData),
+    /// Response from `exchanges/[mic]`.
+    Exchange(ExchangeData),
+    /// Response from `exchanges/[mic]/tickers`.
+    Tickers(ExchangesTickersData),
+    /// Response from `exchanges/[mic]/eod`, `exchanges/[mic]/eod/latest` and
+    /// `exchanges/[mic]/eod/[date]`.
+    Eod(ExchangesEodData),
+    /// Response from `exchanges/[mic]/intraday` and its `latest` and `[date]` variants.
+    Intraday(ExchangesIntradayData),
 }
 
 #[cfg(test)]
 mod tests {
     use chrono::{Datelike, NaiveDate};
 
+    use crate::types::v2::IndexInfoData;
+    use crate::types::EodRow;
     use crate::{
-        CurrenciesData, DividendsData, EodData, EodDataItem, ExchangesData, ExchangesEodData,
-        IntradayData, SplitsData, TickersData, TimezonesData,
+        CurrenciesData, DividendsData, EodData, ExchangeData, ExchangesData, ExchangesEodData,
+        ExchangesIntradayData, ExchangesTickersData, IntradayData, Paginated, SplitsData,
+        TickerData, TickersData, TickersDividendsData, TickersEodLatestData, TickersIntradayData,
+        TickersIntradayLatestData, TickersSplitsData, TimezonesData,
     };
 
     #[test]
@@ -597,6 +830,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_deserialize_ticker() {
+        let json_data = r#"{
+          "name": "Apple Inc",
+          "symbol": "AAPL",
+          "has_intraday": false,
+          "has_eod": true,
+          "country": null,
+          "stock_exchange": {
+            "name": "NASDAQ Stock Exchange",
+            "acronym": "NASDAQ",
+            "mic": "XNAS",
+            "country": "USA",
+            "country_code": "US",
+            "city": "New York",
+            "website": "www.nasdaq.com"
+          }
+        }"#;
+
+        let ticker_data: TickerData = serde_json::from_str(json_data).unwrap();
+        assert_eq!(ticker_data.name, "Apple Inc");
+        assert_eq!(ticker_data.symbol, "AAPL");
+        assert!(ticker_data.has_eod);
+        assert_eq!(ticker_data.country, None);
+        assert_eq!(ticker_data.stock_exchange.mic, "XNAS");
+    }
+
+    #[test]
+    fn test_deserialize_tickers_splits() {
+        let json_data = r#"{
+          "pagination": {
+            "limit": 100,
+            "offset": 0,
+            "count": 2,
+            "total": 2
+          },
+          "data": [
+            {
+              "date": "2020-08-31",
+              "split_factor": 4,
+              "symbol": "AAPL"
+            },
+            {
+              "date": "2014-06-09",
+              "split_factor": 7,
+              "symbol": "AAPL"
+            }
+          ]
+        }"#;
+
+        let tickers_splits_data: TickersSplitsData = serde_json::from_str(json_data).unwrap();
+        assert_eq!(tickers_splits_data.pagination.total, 2);
+        assert_eq!(tickers_splits_data.data[0].split_factor, 4.0);
+        assert_eq!(tickers_splits_data.data[1].date.year(), 2014);
+    }
+
+    #[test]
+    fn test_deserialize_tickers_dividends() {
+        let json_data = r#"{
+          "pagination": {
+            "limit": 100,
+            "offset": 0,
+            "count": 2,
+            "total": 68
+          },
+          "data": [
+            {
+              "date": "2023-08-11",
+              "dividend": 0.24,
+              "symbol": "AAPL"
+            },
+            {
+              "date": "2023-05-12",
+              "dividend": 0.24,
+              "symbol": "AAPL"
+            }
+          ]
+        }"#;
+
+        let tickers_dividends_data: TickersDividendsData = serde_json::from_str(json_data).unwrap();
+        assert!(tickers_dividends_data.has_more());
+        assert_eq!(tickers_dividends_data.data[0].dividend, 0.24);
+        assert_eq!(tickers_dividends_data.data[0].symbol, "AAPL");
+    }
+
     #[test]
     fn test_deserialize_tickers_eod_latest() {
         let json_data = r#"{
@@ -617,7 +935,7 @@ mod tests {
         "date": "2023-10-27T00:00:00+0000"
       }"#;
 
-        let tickers_eod_data: EodDataItem = serde_json::from_str(json_data).unwrap();
+        let tickers_eod_data: TickersEodLatestData = serde_json::from_str(json_data).unwrap();
         assert_eq!(tickers_eod_data.open, 166.91);
         assert_eq!(tickers_eod_data.symbol, "AAPL");
     }
@@ -642,7 +960,7 @@ mod tests {
             "date": "2023-10-27T00:00:00+0000"
           }"#;
 
-        let tickers_eod_data: EodDataItem = serde_json::from_str(json_data).unwrap();
+        let tickers_eod_data: TickersEodLatestData = serde_json::from_str(json_data).unwrap();
         assert_eq!(tickers_eod_data.open, 166.91);
         assert_eq!(tickers_eod_data.date.day(), 27);
     }
@@ -721,6 +1039,76 @@ mod tests {
         assert_eq!(exchanges_data.data[0].currency.name, "US Dollar");
     }
 
+    #[test]
+    fn test_deserialize_exchange() {
+        let json_data = r#"{
+          "name": "NASDAQ Stock Exchange",
+          "acronym": "NASDAQ",
+          "mic": "XNAS",
+          "country": "USA",
+          "country_code": "US",
+          "city": "New York",
+          "website": "WWW.NASDAQ.COM",
+          "timezone": {
+            "timezone": "America/New_York",
+            "abbr": "EST",
+            "abbr_dst": "EDT"
+          },
+          "currency": {
+            "code": "USD",
+            "symbol": "$",
+            "name": "US Dollar"
+          }
+        }"#;
+
+        let exchange_data: ExchangeData = serde_json::from_str(json_data).unwrap();
+        assert_eq!(exchange_data.stock_exchange.mic, "XNAS");
+        assert_eq!(exchange_data.timezone.timezone, "America/New_York");
+        assert_eq!(exchange_data.currency.code, "USD");
+    }
+
+    #[test]
+    fn test_deserialize_exchanges_tickers() {
+        let json_data = r#"{
+          "pagination": {
+            "limit": 2,
+            "offset": 0,
+            "count": 2,
+            "total": 3915
+          },
+          "data": {
+            "name": "NASDAQ Stock Exchange",
+            "acronym": "NASDAQ",
+            "mic": "XNAS",
+            "country": "USA",
+            "city": "New York",
+            "website": "WWW.NASDAQ.COM",
+            "tickers": [
+              {
+                "name": "Microsoft Corporation",
+                "symbol": "MSFT",
+                "has_intraday": false,
+                "has_eod": true
+              },
+              {
+                "name": "Apple Inc",
+                "symbol": "AAPL",
+                "has_intraday": false,
+                "has_eod": true
+              }
+            ]
+          }
+        }"#;
+
+        let exchanges_tickers_data: ExchangesTickersData = serde_json::from_str(json_data).unwrap();
+        assert_eq!(exchanges_tickers_data.pagination.total, 3915);
+        assert_eq!(exchanges_tickers_data.data.mic, "XNAS");
+        assert_eq!(exchanges_tickers_data.data.country_code, None);
+        assert_eq!(exchanges_tickers_data.data.tickers.len(), 2);
+        assert_eq!(exchanges_tickers_data.data.tickers[1].symbol, "AAPL");
+        assert!(exchanges_tickers_data.data.tickers[1].has_eod);
+    }
+
     #[test]
     fn test_deserialize_exchanges_eod() {
         let json_data = r#"{
@@ -788,6 +1176,106 @@ mod tests {
         assert_eq!(exchanges_eod_result.data.eod[0].open, 169.35);
     }
 
+    #[test]
+    fn test_deserialize_tickers_intraday() {
+        let json_data = r#"{
+            "pagination": {
+              "limit": 1,
+              "offset": 0,
+              "count": 1,
+              "total": 11872
+            },
+            "data": {
+              "name": "Apple Inc",
+              "symbol": "AAPL",
+              "has_intraday": true,
+              "has_eod": true,
+              "country": null,
+              "intraday": [
+                {
+                  "open": 173.75,
+                  "high": 176.81,
+                  "low": 173.37,
+                  "last": 176.71,
+                  "close": 177.57,
+                  "volume": 1796985,
+                  "date": "2023-11-03T20:00:00+0000",
+                  "symbol": "AAPL",
+                  "exchange": "IEXG"
+                }
+              ]
+            }
+          }"#;
+
+        let tickers_intraday_data: TickersIntradayData = serde_json::from_str(json_data).unwrap();
+
+        assert_eq!(tickers_intraday_data.pagination.total, 11872);
+        assert_eq!(tickers_intraday_data.data.symbol, "AAPL");
+        assert!(tickers_intraday_data.data.has_intraday);
+        assert_eq!(tickers_intraday_data.data.intraday[0].last, 176.71);
+    }
+
+    #[test]
+    fn test_deserialize_tickers_intraday_latest() {
+        let json_data = r#"{
+            "open": 173.75,
+            "high": 176.81,
+            "low": 173.37,
+            "last": 176.71,
+            "close": 177.57,
+            "volume": 1796985,
+            "date": "2023-11-03T20:00:00+0000",
+            "symbol": "AAPL",
+            "exchange": "IEXG"
+          }"#;
+
+        let tickers_intraday_latest_data: TickersIntradayLatestData =
+            serde_json::from_str(json_data).unwrap();
+
+        assert_eq!(tickers_intraday_latest_data.symbol, "AAPL");
+        assert_eq!(tickers_intraday_latest_data.date.day(), 3);
+    }
+
+    #[test]
+    fn test_deserialize_exchanges_intraday() {
+        let json_data = r#"{
+            "pagination": {
+              "limit": 1,
+              "offset": 0,
+              "count": 1,
+              "total": 250
+            },
+            "data": {
+              "name": "NASDAQ Stock Exchange",
+              "acronym": "NASDAQ",
+              "mic": "XNAS",
+              "country": "USA",
+              "city": "New York",
+              "website": "WWW.NASDAQ.COM",
+              "intraday": [
+                {
+                  "open": 173.75,
+                  "high": 176.81,
+                  "low": 173.37,
+                  "last": 176.71,
+                  "close": 177.57,
+                  "volume": 1796985,
+                  "date": "2023-11-03T20:00:00+0000",
+                  "symbol": "AAPL",
+                  "exchange": "XNAS"
+                }
+              ]
+            }
+          }"#;
+
+        let exchanges_intraday_data: ExchangesIntradayData =
+            serde_json::from_str(json_data).unwrap();
+
+        assert_eq!(exchanges_intraday_data.data.mic, "XNAS");
+        assert_eq!(exchanges_intraday_data.data.intraday.len(), 1);
+        assert_eq!(exchanges_intraday_data.data.intraday[0].exchange, "XNAS");
+    }
+
     #[test]
     fn test_deserialize_intraday() {
         let json_data = r#"{
@@ -842,4 +1330,124 @@ mod tests {
         assert_eq!(intraday_data.data[0].symbol, "AAPL");
         assert_eq!(intraday_data.data[0].exchange, "IEXG");
     }
+
+    fn page(offset: u64, count: u64, total: u64) -> Paginated<u64> {
+        serde_json::from_value(serde_json::json!({
+            "pagination": {
+                "limit": 2,
+                "offset": offset,
+                "count": count,
+                "total": total,
+            },
+            "data": (offset..offset + count).collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_paginated_has_more() {
+        let first = page(0, 2, 5);
+        assert!(first.has_more());
+        assert_eq!(first.next_offset(), Some(2));
+
+        let last = page(4, 1, 5);
+        assert!(!last.has_more());
+        assert_eq!(last.next_offset(), None);
+
+        let empty = page(6, 0, 5);
+        assert!(!empty.has_more());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_paginated_items() {
+        let page = page(2, 2, 5);
+        assert_eq!(page.len(), 2);
+        assert_eq!(page.iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!((&page).into_iter().sum::<u64>(), 5);
+        assert_eq!(page.clone().into_iter().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(page.into_items(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_eod_row_changes() {
+        let json_data = r#"{
+        "pagination": {
+            "limit": 100,
+            "offset": 0,
+            "count": 2,
+            "total": 2
+        },
+        "data": [
+            {
+                "open": 125.0,
+                "high": 155.0,
+                "low": 118.0,
+                "close": 150.0,
+                "volume": 1000.0,
+                "adj_high": 155.0,
+                "adj_low": 118.0,
+                "adj_close": 150.0,
+                "adj_open": 125.0,
+                "adj_volume": 1000.0,
+                "split_factor": 1.0,
+                "dividend": 0.0,
+                "symbol": "AAPL",
+                "exchange": "XNAS",
+                "date": "2025-03-14T00:00:00+0000"
+            },
+            {
+                "open": 125.0,
+                "high": 155.0,
+                "low": 118.0,
+                "close": 120.0,
+                "volume": 1000.0,
+                "adj_high": 155.0,
+                "adj_low": 118.0,
+                "adj_close": 120.0,
+                "adj_open": 125.0,
+                "adj_volume": 1000.0,
+                "split_factor": 1.0,
+                "dividend": 0.0,
+                "symbol": "AAPL",
+                "exchange": "XNAS",
+                "date": "2025-03-13T00:00:00+0000"
+            }
+        ]
+    }"#;
+        let eod: EodData = serde_json::from_str(json_data).unwrap();
+
+        // Rows are ordered from the latest day: 150 on the 14th after 120 on the 13th.
+        let (current, previous) = (&eod.data[0], &eod.data[1]);
+        assert_eq!(current.symbol(), "AAPL");
+        assert_eq!(current.day(), NaiveDate::from_ymd_opt(2025, 3, 14).unwrap());
+        assert_eq!(current.change_since(previous), 30.0);
+        assert_eq!(previous.change_since(current), -30.0);
+        assert_eq!(current.return_since(previous), Some(0.25));
+        assert_eq!(previous.return_since(current), Some(-0.2));
+        assert_eq!(current.return_since(current), Some(0.0));
+    }
+
+    #[test]
+    fn test_eod_row_index_changes() {
+        let index: IndexInfoData = serde_json::from_str(
+            r#"{"data": [
+                {"benchmark": "us500", "price": 5500, "date": "2025-03-13"},
+                {"benchmark": "us500", "price": 5000, "date": "2025-03-12"},
+                {"benchmark": "us500", "price": 0, "date": "2025-03-11"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let (current, previous) = (&index.data[0], &index.data[1]);
+        assert_eq!(current.symbol(), "us500");
+        assert_eq!(current.day(), NaiveDate::from_ymd_opt(2025, 3, 13).unwrap());
+        assert_eq!(current.change_since(previous), 500.0);
+        assert_eq!(current.return_since(previous), Some(0.1));
+        assert_eq!(previous.return_since(current), Some(-500.0 / 5500.0));
+
+        // There is no return against a close of zero.
+        assert_eq!(previous.change_since(&index.data[2]), 5000.0);
+        assert_eq!(previous.return_since(&index.data[2]), None);
+    }
 }
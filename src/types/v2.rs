@@ -0,0 +1,643 @@
+//! Rust types of deserialized responses from version 2 of the Marketstack REST API.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::types::{EodRow, Paginated};
+
+/// Rust representation of single data item from Marketstack `eod` response of version 2.
+///
+/// Version 2 adds a few fields to the rows of version 1.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EodDataItem {
+    /// The fields shared with version 1.
+    #[serde(flatten)]
+    pub eod: crate::types::EodDataItem,
+    /// Name of the given stock ticker.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Type of the asset, e.g. `Stock`.
+    #[serde(default)]
+    pub asset_type: Option<String>,
+    /// Currency the prices are quoted in.
+    #[serde(default)]
+    pub price_currency: Option<String>,
+    /// Acronym of the exchange, e.g. `NASDAQ`.
+    #[serde(default)]
+    pub exchange_code: Option<String>,
+}
+
+/// Rust representation of the JSON response from `eod` marketstack endpoint of version 2.
+pub type EodData = Paginated<EodDataItem>;
+
+impl EodRow for EodDataItem {
+    fn symbol(&self) -> &str {
+        self.eod.symbol()
+    }
+
+    fn day(&self) -> NaiveDate {
+        self.eod.day()
+    }
+
+    fn close(&self) -> f64 {
+        self.eod.close()
+    }
+}
+
+/// Rust representation of the stock exchange of a ticker in `tickerslist`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TickersListExchange {
+    /// Name of the stock exchange.
+    pub name: String,
+    /// Acronym of the stock exchange.
+    pub acronym: String,
+    /// MIC identification of the stock exchange.
+    pub mic: String,
+}
+
+/// Rust representation of a single data item from Marketstack `tickerslist` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TickersListItem {
+    /// Name of the given stock ticker.
+    pub name: String,
+    /// Symbol of the given stock ticker.
+    pub ticker: String,
+    /// Whether intraday data is available for the stock ticker.
+    pub has_intraday: bool,
+    /// Whether eod data is available for the stock ticker.
+    pub has_eod: bool,
+    /// Stock exchange the ticker is traded in.
+    pub stock_exchange: TickersListExchange,
+}
+
+/// Rust representation of the JSON response from `tickerslist` marketstack endpoint.
+pub type TickersListData = Paginated<TickersListItem>;
+
+/// Rust representation of a key executive in `tickerinfo`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct KeyExecutive {
+    /// Name of the executive.
+    pub name: String,
+    /// Role of the executive within the company.
+    #[serde(default)]
+    pub function: Option<String>,
+    /// Salary of the executive, as formatted by Marketstack.
+    #[serde(default)]
+    pub salary: Option<String>,
+    /// Birth year of the executive.
+    #[serde(default)]
+    pub birth_year: Option<String>,
+}
+
+/// Rust representation of a company address in `tickerinfo`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TickerAddress {
+    /// First line of the street address.
+    #[serde(default)]
+    pub street1: Option<String>,
+    /// Second line of the street address.
+    #[serde(default)]
+    pub street2: Option<String>,
+    /// City of the address.
+    #[serde(default)]
+    pub city: Option<String>,
+    /// Postal code of the address.
+    #[serde(default)]
+    pub postal_code: Option<String>,
+    /// Code of the state or country of the address.
+    #[serde(default, rename = "stateOrCountry")]
+    pub state_or_country: Option<String>,
+    /// Name of the state or country of the address.
+    #[serde(default)]
+    pub state_or_country_description: Option<String>,
+}
+
+/// Rust representation of the company details from Marketstack `tickerinfo` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TickerInfo {
+    /// Name of the company.
+    pub name: String,
+    /// Symbol of the given stock ticker.
+    pub ticker: String,
+    /// Type of the item, e.g. `equity`.
+    #[serde(default)]
+    pub item_type: Option<String>,
+    /// Sector of the company.
+    #[serde(default)]
+    pub sector: Option<String>,
+    /// Industry of the company.
+    #[serde(default)]
+    pub industry: Option<String>,
+    /// Acronym of the exchange the ticker is listed on.
+    #[serde(default)]
+    pub exchange_code: Option<String>,
+    /// Number of full time employees, as formatted by Marketstack.
+    #[serde(default)]
+    pub full_time_employees: Option<String>,
+    /// Date of the initial public offering.
+    #[serde(default)]
+    pub ipo_date: Option<String>,
+    /// Date the company was founded.
+    #[serde(default)]
+    pub date_founded: Option<String>,
+    /// Key executives of the company.
+    #[serde(default)]
+    pub key_executives: Vec<KeyExecutive>,
+    /// Currency the company reports in.
+    #[serde(default)]
+    pub reporting_currency: Option<String>,
+    /// Address of the company.
+    #[serde(default)]
+    pub address: Option<TickerAddress>,
+    /// Phone number of the company.
+    #[serde(default)]
+    pub phone: Option<String>,
+    /// Website URL of the company.
+    #[serde(default)]
+    pub website: Option<String>,
+    /// Description of the company.
+    #[serde(default)]
+    pub about: Option<String>,
+}
+
+/// Rust representation of the JSON response from `tickerinfo` marketstack endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TickerInfoData {
+    /// Corresponds to data entry from JSON response from marketstack.
+    pub data: TickerInfo,
+}
+
+/// Deserialize a number which Marketstack may send as a string, with thousands separators.
+fn number_or_string<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Number {
+        Number(f64),
+        String(String),
+    }
+
+    match Number::deserialize(deserializer)? {
+        Number::Number(number) => Ok(number),
+        Number::String(string) => string
+            .replace(',', "")
+            .parse()
+            .map_err(serde::de::Error::custom),
+    }
+}
+
+/// Deserialize a number like [`number_or_string`], where `null` means that there is none.
+fn optional_number_or_string<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Number(#[serde(deserialize_with = "number_or_string")] f64);
+
+    Ok(Option::<Number>::deserialize(deserializer)?.map(|Number(number)| number))
+}
+
+/// Rust representation of a single data item from Marketstack `stockprice` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StockPriceDataItem {
+    /// Symbol of the given stock ticker.
+    pub ticker: String,
+    /// Price of the latest trade.
+    #[serde(deserialize_with = "number_or_string")]
+    pub price: f64,
+    /// Currency the price is quoted in.
+    #[serde(default)]
+    pub currency: Option<String>,
+    /// Time of the latest trade, as formatted by Marketstack.
+    #[serde(default)]
+    pub trade_last: Option<String>,
+    /// Acronym of the exchange of the trade, e.g. `NASDAQ`.
+    #[serde(default)]
+    pub exchange_code: Option<String>,
+    /// Name of the exchange of the trade.
+    #[serde(default)]
+    pub exchange_name: Option<String>,
+    /// Country of the exchange of the trade.
+    #[serde(default)]
+    pub country: Option<String>,
+}
+
+/// Rust representation of the JSON response from `stockprice` marketstack endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StockPriceData {
+    /// Corresponds to data entry from JSON response from marketstack.
+    pub data: Vec<StockPriceDataItem>,
+}
+
+/// Rust representation of a single data item from Marketstack `commodities` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CommoditiesDataItem {
+    /// Name of the commodity.
+    pub commodity_name: String,
+    /// Unit the price is quoted in, e.g. `usd/t`.
+    #[serde(default)]
+    pub commodity_unit: Option<String>,
+    /// Latest price of the commodity.
+    #[serde(deserialize_with = "number_or_string")]
+    pub commodity_price: f64,
+    /// Change of the price over the day, as formatted by Marketstack.
+    #[serde(default)]
+    pub price_change_day: Option<String>,
+    /// Percentage change of the price over the day, e.g. `-0.11%`.
+    #[serde(default)]
+    pub percentage_day: Option<String>,
+    /// Percentage change of the price over the week.
+    #[serde(default)]
+    pub percentage_week: Option<String>,
+    /// Percentage change of the price over the month.
+    #[serde(default)]
+    pub percentage_month: Option<String>,
+    /// Percentage change of the price over the year.
+    #[serde(default)]
+    pub percentage_year: Option<String>,
+}
+
+/// Rust representation of the JSON response from `commodities` marketstack endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CommoditiesData {
+    /// Corresponds to data entry from JSON response from marketstack.
+    pub data: Vec<CommoditiesDataItem>,
+}
+
+/// Rust representation of a single data item from Marketstack `commoditieshistory` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CommoditiesHistoryDataItem {
+    /// Date of the price.
+    pub date: NaiveDate,
+    /// Name of the commodity.
+    pub commodity_name: String,
+    /// Unit the price is quoted in, e.g. `usd/t`.
+    #[serde(default)]
+    pub commodity_unit: Option<String>,
+    /// Price of the commodity on the date.
+    #[serde(deserialize_with = "number_or_string")]
+    pub commodity_price: f64,
+}
+
+/// Rust representation of the JSON response from `commoditieshistory` marketstack endpoint.
+pub type CommoditiesHistoryData = Paginated<CommoditiesHistoryDataItem>;
+
+/// Rust representation of a single data item from Marketstack `indexlist` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IndexListDataItem {
+    /// Identifier of the index, e.g. `us500`.
+    pub benchmark: String,
+}
+
+/// Rust representation of the JSON response from `indexlist` marketstack endpoint.
+pub type IndexListData = Paginated<IndexListDataItem>;
+
+/// Rust representation of a single data item from Marketstack `indexinfo` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IndexInfoDataItem {
+    /// Identifier of the index, e.g. `us500`.
+    pub benchmark: String,
+    /// Region the index covers.
+    #[serde(default)]
+    pub region: Option<String>,
+    /// Country the index covers.
+    #[serde(default)]
+    pub country: Option<String>,
+    /// Level of the index.
+    #[serde(deserialize_with = "number_or_string")]
+    pub price: f64,
+    /// Change of the level over the day, as formatted by Marketstack.
+    #[serde(default)]
+    pub price_change_day: Option<String>,
+    /// Percentage change of the level over the day, e.g. `-0.11%`.
+    #[serde(default)]
+    pub percentage_day: Option<String>,
+    /// Percentage change of the level over the week.
+    #[serde(default)]
+    pub percentage_week: Option<String>,
+    /// Percentage change of the level over the month.
+    #[serde(default)]
+    pub percentage_month: Option<String>,
+    /// Percentage change of the level over the year.
+    #[serde(default)]
+    pub percentage_year: Option<String>,
+    /// Date of the level.
+    pub date: NaiveDate,
+}
+
+impl EodRow for IndexInfoDataItem {
+    fn symbol(&self) -> &str {
+        &self.benchmark
+    }
+
+    fn day(&self) -> NaiveDate {
+        self.date
+    }
+
+    fn close(&self) -> f64 {
+        self.price
+    }
+}
+
+/// Rust representation of the JSON response from `indexinfo` marketstack endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct IndexInfoData {
+    /// Corresponds to data entry from JSON response from marketstack.
+    pub data: Vec<IndexInfoDataItem>,
+}
+
+/// Rust representation of a single data item from Marketstack `etflist` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EtfListDataItem {
+    /// Ticker symbol of the ETF.
+    pub ticker: String,
+    /// Name of the ETF.
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// Rust representation of the JSON response from `etflist` marketstack endpoint.
+pub type EtfListData = Paginated<EtfListDataItem>;
+
+/// Rust representation of a constituent of an ETF.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EtfHolding {
+    /// Ticker symbol of the constituent, if it is listed.
+    #[serde(default)]
+    pub symbol: Option<String>,
+    /// Name of the constituent.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// ISIN of the constituent.
+    #[serde(default)]
+    pub isin: Option<String>,
+    /// Share of the constituent in the net assets of the ETF, in percent, if reported.
+    #[serde(default, deserialize_with = "optional_number_or_string")]
+    pub weight: Option<f64>,
+    /// Number of shares of the constituent held, if reported.
+    #[serde(default, deserialize_with = "optional_number_or_string")]
+    pub shares: Option<f64>,
+    /// Market value of the shares held, if reported.
+    #[serde(default, deserialize_with = "optional_number_or_string")]
+    pub market_value: Option<f64>,
+}
+
+/// Rust representation of a single data item from Marketstack `etfholdings` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EtfHoldingsDataItem {
+    /// Ticker symbol of the ETF.
+    pub ticker: String,
+    /// Date the holdings were reported for.
+    pub date: NaiveDate,
+    /// Constituents of the ETF.
+    pub holdings: Vec<EtfHolding>,
+}
+
+/// Rust representation of the JSON response from `etfholdings` marketstack endpoint.
+pub type EtfHoldingsData = Paginated<EtfHoldingsDataItem>;
+
+/// Rust representation of a single data item from Marketstack `bondlist` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BondListDataItem {
+    /// Country of the bonds, as used by `bond/[country]`.
+    pub country: String,
+    /// Region of the country.
+    #[serde(default)]
+    pub region: Option<String>,
+}
+
+/// Rust representation of the JSON response from `bondlist` marketstack endpoint.
+pub type BondListData = Paginated<BondListDataItem>;
+
+/// Rust representation of a single data item from Marketstack `bond/[country]` response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BondDataItem {
+    /// Country of the bond.
+    pub country: String,
+    /// Region of the country.
+    #[serde(default)]
+    pub region: Option<String>,
+    /// Maturity of the bond, e.g. `10Y`.
+    #[serde(rename = "type")]
+    pub maturity: String,
+    /// Yield of the bond, in percent.
+    #[serde(rename = "yield", deserialize_with = "number_or_string")]
+    pub yield_value: f64,
+    /// Change of the yield over the day, as formatted by Marketstack.
+    #[serde(default)]
+    pub price_change_day: Option<String>,
+    /// Percentage change of the yield over the week.
+    #[serde(default)]
+    pub percentage_week: Option<String>,
+    /// Percentage change of the yield over the month.
+    #[serde(default)]
+    pub percentage_month: Option<String>,
+    /// Percentage change of the yield over the year.
+    #[serde(default)]
+    pub percentage_year: Option<String>,
+    /// Date of the yield.
+    #[serde(default)]
+    pub date: Option<NaiveDate>,
+}
+
+/// Rust representation of the JSON response from `bond/[country]` marketstack endpoint.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BondData {
+    /// Corresponds to data entry from JSON response from marketstack.
+    pub data: Vec<BondDataItem>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use crate::types::v2::{
+        EodData, IndexInfoData, IndexListData, StockPriceData, TickerInfoData, TickersListData,
+    };
+    use crate::types::EodRow;
+
+    #[test]
+    fn test_deserialize_eod() {
+        let json_data = r#"{
+        "pagination": {
+            "limit": 100,
+            "offset": 0,
+            "count": 1,
+            "total": 1
+        },
+        "data": [
+            {
+                "open": 228.46,
+                "high": 229.52,
+                "low": 227.3,
+                "close": 227.79,
+                "volume": 34025967.0,
+                "adj_high": 229.52,
+                "adj_low": 227.3,
+                "adj_close": 227.79,
+                "adj_open": 228.46,
+                "adj_volume": 34025967.0,
+                "split_factor": 1.0,
+                "dividend": 0.0,
+                "name": "Apple Inc",
+                "exchange_code": "NASDAQ",
+                "asset_type": "Stock",
+                "price_currency": "usd",
+                "symbol": "AAPL",
+                "exchange": "XNAS",
+                "date": "2024-09-27T00:00:00+0000"
+            }
+        ]
+    }"#;
+
+        let eod_data: EodData = serde_json::from_str(json_data).unwrap();
+        assert_eq!(eod_data.data[0].eod.open, 228.46);
+        assert_eq!(eod_data.data[0].name.as_deref(), Some("Apple Inc"));
+        assert_eq!(eod_data.data[0].exchange_code.as_deref(), Some("NASDAQ"));
+    }
+
+    #[test]
+    fn test_deserialize_tickerslist() {
+        let json_data = r#"{
+        "pagination": {
+            "limit": 100,
+            "offset": 0,
+            "count": 1,
+            "total": 1
+        },
+        "data": [
+            {
+                "name": "Microsoft Corporation",
+                "ticker": "MSFT",
+                "has_intraday": false,
+                "has_eod": true,
+                "stock_exchange": {
+                    "name": "NASDAQ - ALL MARKETS",
+                    "acronym": "NASDAQ",
+                    "mic": "XNAS"
+                }
+            }
+        ]
+    }"#;
+
+        let tickers: TickersListData = serde_json::from_str(json_data).unwrap();
+        assert_eq!(tickers.data[0].ticker, "MSFT");
+        assert!(tickers.data[0].has_eod);
+        assert_eq!(tickers.data[0].stock_exchange.acronym, "NASDAQ");
+    }
+
+    #[test]
+    fn test_deserialize_tickerinfo() {
+        let json_data = r#"{
+        "data": {
+            "name": "Microsoft Corporation",
+            "ticker": "MSFT",
+            "item_type": "equity",
+            "sector": "Technology",
+            "industry": "Software—Infrastructure",
+            "exchange_code": "NASDAQ",
+            "full_time_employees": "221000",
+            "ipo_date": null,
+            "date_founded": null,
+            "key_executives": [],
+            "reporting_currency": "usd",
+            "address": {
+                "city": "REDMOND",
+                "street1": "ONE MICROSOFT WAY",
+                "street2": null,
+                "postal_code": "98052-6399",
+                "stateOrCountry": "WA",
+                "state_or_country_description": "WA"
+            },
+            "phone": "425-882-8080",
+            "website": "https://www.microsoft.com"
+        }
+    }"#;
+
+        let info: TickerInfoData = serde_json::from_str(json_data).unwrap();
+        assert_eq!(info.data.name, "Microsoft Corporation");
+        assert_eq!(info.data.date_founded, None);
+        let address = info.data.address.unwrap();
+        assert_eq!(address.state_or_country.as_deref(), Some("WA"));
+        assert_eq!(address.street2, None);
+    }
+
+    #[test]
+    fn test_deserialize_stockprice() {
+        let json_data = r#"{
+        "data": [
+            {
+                "exchange_code": "NASDAQ",
+                "exchange_name": "NASDAQ - ALL MARKETS",
+                "country": "USA",
+                "ticker": "AAPL",
+                "price": "213.49",
+                "currency": "USD",
+                "trade_last": "2025-03-14 16:00:00"
+            },
+            {
+                "ticker": "AAPL",
+                "price": 213.5
+            }
+        ]
+    }"#;
+
+        let stock_price: StockPriceData = serde_json::from_str(json_data).unwrap();
+        assert_eq!(stock_price.data[0].price, 213.49);
+        assert_eq!(stock_price.data[0].currency.as_deref(), Some("USD"));
+        assert_eq!(stock_price.data[1].price, 213.5);
+        assert_eq!(stock_price.data[1].exchange_code, None);
+    }
+
+    #[test]
+    fn test_deserialize_indexlist() {
+        let json_data = r#"{
+        "pagination": {
+            "limit": 100,
+            "offset": 0,
+            "count": 2,
+            "total": 2
+        },
+        "data": [
+            {
+                "benchmark": "us500"
+            },
+            {
+                "benchmark": "aex"
+            }
+        ]
+    }"#;
+
+        let indices: IndexListData = serde_json::from_str(json_data).unwrap();
+        assert_eq!(indices.data[1].benchmark, "aex");
+    }
+
+    #[test]
+    fn test_deserialize_indexinfo() {
+        let json_data = r#"{
+        "data": [
+            {
+                "benchmark": "us500",
+                "region": "united states",
+                "country": "united states",
+                "price": "5,638.94",
+                "price_change_day": "117.42",
+                "percentage_day": "2.13%",
+                "percentage_week": "-0.47%",
+                "percentage_month": "-5.67%",
+                "percentage_year": "10.31%",
+                "date": "2025-03-14"
+            }
+        ]
+    }"#;
+
+        let info: IndexInfoData = serde_json::from_str(json_data).unwrap();
+        assert_eq!(info.data[0].price, 5638.94);
+        assert_eq!(
+            info.data[0].day(),
+            NaiveDate::from_ymd_opt(2025, 3, 14).unwrap()
+        );
+    }
+}
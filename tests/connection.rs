@@ -0,0 +1,62 @@
+use std::net::TcpListener;
+
+use marketstack::{AsyncMarketstack, ConnectionError, Marketstack, MarketstackError};
+
+/// An address on which nothing is listening.
+fn closed_host() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    format!("127.0.0.1:{}", port)
+}
+
+#[test]
+fn test_eager_build_fails_without_network() {
+    let err = Marketstack::builder(closed_host(), "token")
+        .insecure()
+        .build()
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            err,
+            MarketstackError::Connection {
+                source: ConnectionError::Network { .. },
+            },
+        ),
+        "unexpected error: {}",
+        err,
+    );
+}
+
+#[test]
+fn test_lazy_build_makes_no_request() {
+    let client = Marketstack::builder(closed_host(), "token")
+        .insecure()
+        .lazy()
+        .build()
+        .unwrap();
+
+    let err = client.verify().unwrap_err();
+    assert!(
+        matches!(err, ConnectionError::Network { .. }),
+        "unexpected error: {}",
+        err,
+    );
+}
+
+#[tokio::test]
+async fn test_async_lazy_build_makes_no_request() {
+    let client: AsyncMarketstack = Marketstack::builder(closed_host(), "token")
+        .insecure()
+        .lazy()
+        .build_async()
+        .await
+        .unwrap();
+
+    let err = client.verify().await.unwrap_err();
+    assert!(
+        matches!(err, ConnectionError::Network { .. }),
+        "unexpected error: {}",
+        err,
+    );
+}
@@ -1,13 +1,11 @@
 use marketstack::api::{currencies, AsyncQuery, Query};
-use marketstack::{AsyncMarketstack, CurrenciesData, Marketstack};
+use marketstack::CurrenciesData;
 
 mod setup;
 
 #[test]
-#[ignore]
 fn test_currencies() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("currencies/currencies");
 
     let endpoint = currencies::Currencies::builder()
         .limit(3)
@@ -23,12 +21,8 @@ fn test_currencies() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_async_currencies() {
-    let api_key = setup::setup_key();
-    let client = AsyncMarketstack::new_insecure("api.marketstack.com", api_key)
-        .await
-        .unwrap();
+    let client = setup::cassette_async("currencies/async_currencies").await;
 
     let endpoint = currencies::Currencies::builder()
         .limit(3)
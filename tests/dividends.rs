@@ -1,15 +1,13 @@
 use chrono::NaiveDate;
 
 use marketstack::api::{dividends, AsyncQuery, Query};
-use marketstack::{AsyncMarketstack, DividendsData, Marketstack};
+use marketstack::DividendsData;
 
 mod setup;
 
 #[test]
-#[ignore]
 fn test_dividends() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("dividends/dividends");
 
     let endpoint = dividends::Dividends::builder()
         .symbol("AAPL")
@@ -26,12 +24,8 @@ fn test_dividends() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_async_dividends() {
-    let api_key = setup::setup_key();
-    let client = AsyncMarketstack::new_insecure("api.marketstack.com", api_key)
-        .await
-        .unwrap();
+    let client = setup::cassette_async("dividends/async_dividends").await;
 
     let endpoint = dividends::Dividends::builder()
         .limit(3)
@@ -48,10 +42,8 @@ async fn test_async_dividends() {
 }
 
 #[test]
-#[ignore]
 fn test_dividends_date() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("dividends/dividends_date");
 
     let endpoint = dividends::Dividends::builder()
         .symbol("AAPL")
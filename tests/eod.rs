@@ -1,14 +1,12 @@
 use marketstack::api::common::SortOrder;
-use marketstack::api::{eod, AsyncQuery, Query};
-use marketstack::{AsyncMarketstack, EodData, Marketstack};
+use marketstack::api::{eod, AsyncQuery, Query, TypedEndpoint};
+use marketstack::EodData;
 
 mod setup;
 
 #[test]
-#[ignore]
 fn test_eod() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("eod/eod");
 
     let endpoint = eod::Eod::builder().symbol("AAPL").build().unwrap();
     let eod_result: EodData = endpoint.query(&client).unwrap();
@@ -20,10 +18,8 @@ fn test_eod() {
 }
 
 #[test]
-#[ignore]
 fn test_eod_paged() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("eod/eod_paged");
 
     let endpoint = eod::Eod::builder()
         .symbol("AAPL")
@@ -38,10 +34,8 @@ fn test_eod_paged() {
 }
 
 #[test]
-#[ignore]
 fn test_eod_sorting() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("eod/eod_sorting");
 
     let endpoint = eod::Eod::builder()
         .symbol("AAPL")
@@ -53,12 +47,8 @@ fn test_eod_sorting() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_async_eod() {
-    let api_key = setup::setup_key();
-    let client = AsyncMarketstack::new_insecure("api.marketstack.com", api_key)
-        .await
-        .unwrap();
+    let client = setup::cassette_async("eod/async_eod").await;
 
     let endpoint = eod::Eod::builder().symbol("AAPL").build().unwrap();
     let eod_result: EodData = endpoint.query_async(&client).await.unwrap();
@@ -70,12 +60,8 @@ async fn test_async_eod() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_async_eod_paged() {
-    let api_key = setup::setup_key();
-    let client = AsyncMarketstack::new_insecure("api.marketstack.com", api_key)
-        .await
-        .unwrap();
+    let client = setup::cassette_async("eod/async_eod_paged").await;
 
     let endpoint = eod::Eod::builder()
         .symbol("AAPL")
@@ -88,3 +74,23 @@ async fn test_async_eod_paged() {
     assert_eq!(eod_result.pagination.limit, 5);
     assert_eq!(eod_result.data.len(), 5);
 }
+
+#[test]
+fn test_eod_fetch() {
+    let client = setup::cassette("eod/eod");
+
+    let endpoint = eod::Eod::builder().symbol("AAPL").build().unwrap();
+    let eod_result = endpoint.fetch(&client).unwrap();
+
+    assert_eq!(eod_result.data.len(), 100);
+}
+
+#[tokio::test]
+async fn test_eod_fetch_async() {
+    let client = setup::cassette_async("eod/eod").await;
+
+    let endpoint = eod::Eod::builder().symbol("AAPL").build().unwrap();
+    let eod_result = endpoint.fetch_async(&client).await.unwrap();
+
+    assert_eq!(eod_result.data.len(), 100);
+}
@@ -2,15 +2,13 @@ use chrono::NaiveDate;
 use marketstack::api::common::SortOrder;
 use marketstack::api::eod::Eod;
 use marketstack::api::{AsyncQuery, Query};
-use marketstack::{AsyncMarketstack, EodData, Marketstack};
+use marketstack::EodData;
 
 mod setup;
 
 #[test]
-#[ignore]
 fn test_eod_date() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("eod_date/eod_date");
 
     let endpoint = Eod::builder()
         .date(NaiveDate::from_ymd_opt(2023, 9, 29).unwrap())
@@ -26,10 +24,8 @@ fn test_eod_date() {
 }
 
 #[test]
-#[ignore]
 fn test_eod_date_paged() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("eod_date/eod_date_paged");
 
     let endpoint = Eod::builder()
         .date(NaiveDate::from_ymd_opt(2023, 9, 29).unwrap())
@@ -45,10 +41,8 @@ fn test_eod_date_paged() {
 }
 
 #[test]
-#[ignore]
 fn test_eod_date_sorting() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("eod_date/eod_date_sorting");
 
     let endpoint = Eod::builder()
         .symbol("AAPL")
@@ -61,12 +55,8 @@ fn test_eod_date_sorting() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_async_eod_date() {
-    let api_key = setup::setup_key();
-    let client = AsyncMarketstack::new_insecure("api.marketstack.com", api_key)
-        .await
-        .unwrap();
+    let client = setup::cassette_async("eod_date/async_eod_date").await;
 
     let endpoint = Eod::builder()
         .date(NaiveDate::from_ymd_opt(2023, 9, 29).unwrap())
@@ -82,12 +72,8 @@ async fn test_async_eod_date() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_async_eod_date_paged() {
-    let api_key = setup::setup_key();
-    let client = AsyncMarketstack::new_insecure("api.marketstack.com", api_key)
-        .await
-        .unwrap();
+    let client = setup::cassette_async("eod_date/async_eod_date_paged").await;
 
     let endpoint = Eod::builder()
         .symbol("AAPL")
@@ -1,15 +1,13 @@
 use marketstack::api::common::SortOrder;
 use marketstack::api::eod;
 use marketstack::api::{AsyncQuery, Query};
-use marketstack::{AsyncMarketstack, EodData, Marketstack};
+use marketstack::EodData;
 
 mod setup;
 
 #[test]
-#[ignore]
 fn test_eod_latest() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("eod_latest/eod_latest");
 
     let endpoint = eod::Eod::builder()
         .latest(true)
@@ -25,10 +23,8 @@ fn test_eod_latest() {
 }
 
 #[test]
-#[ignore]
 fn test_eod_latest_paged() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("eod_latest/eod_latest_paged");
 
     let endpoint = eod::Eod::builder()
         .symbol("AAPL")
@@ -44,10 +40,8 @@ fn test_eod_latest_paged() {
 }
 
 #[test]
-#[ignore]
 fn test_eod_latest_sorting() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("eod_latest/eod_latest_sorting");
 
     let endpoint = eod::Eod::builder()
         .symbol("AAPL")
@@ -60,12 +54,8 @@ fn test_eod_latest_sorting() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_async_eod_latest() {
-    let api_key = setup::setup_key();
-    let client = AsyncMarketstack::new_insecure("api.marketstack.com", api_key)
-        .await
-        .unwrap();
+    let client = setup::cassette_async("eod_latest/async_eod_latest").await;
 
     let endpoint = eod::Eod::builder()
         .latest(true)
@@ -81,12 +71,8 @@ async fn test_async_eod_latest() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_async_eod_paged() {
-    let api_key = setup::setup_key();
-    let client = AsyncMarketstack::new_insecure("api.marketstack.com", api_key)
-        .await
-        .unwrap();
+    let client = setup::cassette_async("eod_latest/async_eod_paged").await;
 
     let endpoint = eod::Eod::builder()
         .symbol("AAPL")
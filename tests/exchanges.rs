@@ -1,16 +1,16 @@
 use chrono::NaiveDate;
 use marketstack::api::eod::Eod;
 use marketstack::api::exchanges::Exchanges;
-use marketstack::api::Query;
-use marketstack::{ExchangesData, ExchangesDataItem, ExchangesEodData, Marketstack};
+use marketstack::api::{Query, TypedEndpoint};
+use marketstack::{
+    ExchangesData, ExchangesDataItem, ExchangesEodData, ExchangesResponse, ExchangesTickersData,
+};
 
 mod setup;
 
 #[test]
-#[ignore]
 fn test_exchanges() {
-    let api_key = setup::setup_key();
-    let client = marketstack::Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("exchanges/exchanges");
 
     let endpoint = Exchanges::builder().limit(25).unwrap().build().unwrap();
     let exchanges_result: ExchangesData = endpoint.query(&client).unwrap();
@@ -20,10 +20,8 @@ fn test_exchanges() {
 }
 
 #[test]
-#[ignore]
 fn test_exchanges_mic() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("exchanges/exchanges_mic");
 
     let endpoint = Exchanges::builder().mic("XNAS").build().unwrap();
 
@@ -48,10 +46,8 @@ fn test_exchanges_mic() {
 }
 
 #[test]
-#[ignore]
 fn test_exchanges_mic_eod() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("exchanges/exchanges_mic_eod");
 
     let endpoint = Exchanges::builder()
         .mic("XNAS")
@@ -65,10 +61,8 @@ fn test_exchanges_mic_eod() {
 }
 
 #[test]
-#[ignore]
 fn test_exchanges_mic_eod_latest() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("exchanges/exchanges_mic_eod_latest");
 
     let endpoint = Exchanges::builder()
         .mic("XNAS")
@@ -82,10 +76,8 @@ fn test_exchanges_mic_eod_latest() {
 }
 
 #[test]
-#[ignore]
 fn test_exchanges_mic_eod_date() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("exchanges/exchanges_mic_eod_date");
 
     let endpoint = Exchanges::builder()
         .mic("XNAS")
@@ -104,3 +96,33 @@ fn test_exchanges_mic_eod_date() {
     assert_eq!(exchanges_eod_date_result.data.eod[0].symbol, "AAPL");
     assert_eq!(exchanges_eod_date_result.data.eod[0].volume, 66830700_f64);
 }
+
+#[test]
+fn test_exchanges_mic_fetch() {
+    let client = setup::cassette("exchanges/exchanges_mic");
+
+    let endpoint = Exchanges::builder().mic("XNAS").build().unwrap();
+
+    match endpoint.fetch(&client).unwrap() {
+        ExchangesResponse::Exchange(exchange) => assert_eq!(exchange.stock_exchange.mic, "XNAS"),
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[test]
+fn test_exchanges_mic_tickers() {
+    let client = setup::cassette("exchanges/exchanges_mic_tickers");
+
+    let endpoint = Exchanges::builder()
+        .mic("XNAS")
+        .tickers("tickers")
+        .limit(2)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let exchanges_tickers_result: ExchangesTickersData = endpoint.query(&client).unwrap();
+
+    assert_eq!(exchanges_tickers_result.data.mic, "XNAS");
+    assert_eq!(exchanges_tickers_result.data.tickers.len(), 2);
+}
@@ -0,0 +1,314 @@
+use std::error::Error;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use marketstack::api::currencies::Currencies;
+use marketstack::api::v2::tickerslist::TickersList;
+use marketstack::api::{AsyncQuery, Query, TypedEndpoint};
+use marketstack::{ApiVersion, CurrenciesData, Marketstack};
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::Proxy;
+use url::Url;
+
+const EMPTY: &str = r#"{"pagination":{"limit":100,"offset":0,"count":0,"total":0},"data":[]}"#;
+
+/// A request head as received by the server, with lowercase header names.
+struct Received {
+    request_line: String,
+    headers: Vec<(String, String)>,
+}
+
+impl Received {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Serve an empty response to every request, reporting each request head.
+///
+/// Returns the `host:port` the server listens on.
+fn serve() -> (String, Receiver<Received>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let (send, recv) = mpsc::channel();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut reader = BufReader::new(stream.unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut headers = Vec::new();
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+                if let Some((key, value)) = line.trim_end().split_once(':') {
+                    headers.push((key.to_lowercase(), value.trim().into()));
+                }
+                line.clear();
+            }
+            let _ = send.send(Received {
+                request_line: request_line.trim_end().into(),
+                headers,
+            });
+
+            let mut stream = reader.into_inner();
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                EMPTY.len(),
+                EMPTY,
+            );
+        }
+    });
+
+    (format!("127.0.0.1:{}", port), recv)
+}
+
+/// Accept connections without ever responding.
+fn serve_silently() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    thread::spawn(move || {
+        let mut open = Vec::new();
+        for stream in listener.incoming() {
+            open.push(stream);
+        }
+    });
+
+    format!("127.0.0.1:{}", port)
+}
+
+fn query(client: &Marketstack) -> CurrenciesData {
+    Currencies::builder()
+        .build()
+        .unwrap()
+        .query(client)
+        .unwrap()
+}
+
+#[test]
+fn test_user_agent_and_headers() {
+    let (host, recv) = serve();
+    let mut headers = HeaderMap::new();
+    headers.insert("x-request-source", HeaderValue::from_static("tests"));
+
+    let client = Marketstack::builder(host, "token")
+        .insecure()
+        .lazy()
+        .user_agent("marketstack-tests/1.0")
+        .default_headers(headers)
+        .build()
+        .unwrap();
+    query(&client);
+
+    let received = recv.recv().unwrap();
+    assert_eq!(received.header("user-agent"), Some("marketstack-tests/1.0"));
+    assert_eq!(received.header("x-request-source"), Some("tests"));
+}
+
+#[test]
+fn test_compression() {
+    let (host, recv) = serve();
+
+    let client = Marketstack::builder(&host, "token")
+        .insecure()
+        .lazy()
+        .build()
+        .unwrap();
+    query(&client);
+
+    let accept = recv.recv().unwrap();
+    let accept = accept.header("accept-encoding").unwrap();
+    assert!(accept.contains("gzip"), "{}", accept);
+    assert!(accept.contains("br"), "{}", accept);
+
+    let client = Marketstack::builder(&host, "token")
+        .insecure()
+        .lazy()
+        .gzip(false)
+        .brotli(false)
+        .build()
+        .unwrap();
+    query(&client);
+
+    assert_eq!(recv.recv().unwrap().header("accept-encoding"), None);
+}
+
+#[test]
+fn test_proxy() {
+    let (proxy, recv) = serve();
+
+    let client = Marketstack::builder("marketstack.host.invalid", "token")
+        .insecure()
+        .lazy()
+        .proxy(Proxy::http(format!("http://{}", proxy)).unwrap())
+        .build()
+        .unwrap();
+    query(&client);
+
+    let received = recv.recv().unwrap();
+    assert_eq!(
+        received.request_line,
+        "GET http://marketstack.host.invalid/v1/currencies?access_key=token HTTP/1.1",
+    );
+}
+
+#[test]
+fn test_timeout() {
+    let host = serve_silently();
+
+    let client = Marketstack::builder(host, "token")
+        .insecure()
+        .lazy()
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+    let err = Currencies::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .map(|_: CurrenciesData| ())
+        .unwrap_err();
+
+    let mut source: Option<&(dyn Error + 'static)> = Some(&err);
+    let mut timed_out = false;
+    while let Some(err) = source {
+        timed_out |= err
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(reqwest::Error::is_timeout);
+        source = err.source();
+    }
+    assert!(timed_out, "unexpected error: {:?}", err);
+}
+
+#[test]
+fn test_injected_client() {
+    let (host, recv) = serve();
+    let http_client = reqwest::blocking::Client::builder()
+        .user_agent("injected")
+        .build()
+        .unwrap();
+
+    let client = Marketstack::builder(host, "token")
+        .insecure()
+        .lazy()
+        .user_agent("ignored")
+        .http_client(http_client)
+        .build()
+        .unwrap();
+    query(&client);
+
+    assert_eq!(recv.recv().unwrap().header("user-agent"), Some("injected"));
+}
+
+#[tokio::test]
+async fn test_async_injected_client() {
+    let (host, recv) = serve();
+    let http_client = reqwest::Client::builder()
+        .user_agent("injected")
+        .build()
+        .unwrap();
+
+    let client = Marketstack::builder(host, "token")
+        .insecure()
+        .lazy()
+        .async_http_client(http_client)
+        .build_async()
+        .await
+        .unwrap();
+    let _: CurrenciesData = Currencies::builder()
+        .build()
+        .unwrap()
+        .query_async(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(recv.recv().unwrap().header("user-agent"), Some("injected"));
+}
+
+#[test]
+fn test_base_url() {
+    let (host, recv) = serve();
+    let base_url = Url::parse(&format!("http://{}/marketstack", host)).unwrap();
+
+    let client = Marketstack::builder("ignored.host.invalid", "token")
+        .lazy()
+        .base_url(base_url)
+        .build()
+        .unwrap();
+    query(&client);
+
+    assert_eq!(
+        recv.recv().unwrap().request_line,
+        "GET /marketstack/v1/currencies?access_key=token HTTP/1.1",
+    );
+}
+
+#[test]
+fn test_api_version() {
+    let (host, recv) = serve();
+
+    let client = Marketstack::builder(host, "token")
+        .insecure()
+        .lazy()
+        .api_version(ApiVersion::V2)
+        .build()
+        .unwrap();
+    query(&client);
+
+    assert_eq!(
+        recv.recv().unwrap().request_line,
+        "GET /v2/currencies?access_key=token HTTP/1.1",
+    );
+}
+
+#[tokio::test]
+async fn test_async_base_url_and_api_version() {
+    let (host, recv) = serve();
+    let base_url = Url::parse(&format!("http://{}/proxy/marketstack/", host)).unwrap();
+
+    let client = Marketstack::builder("ignored.host.invalid", "token")
+        .lazy()
+        .base_url(base_url)
+        .api_version(ApiVersion::V2)
+        .build_async()
+        .await
+        .unwrap();
+    let _: CurrenciesData = Currencies::builder()
+        .build()
+        .unwrap()
+        .query_async(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        recv.recv().unwrap().request_line,
+        "GET /proxy/marketstack/v2/currencies?access_key=token HTTP/1.1",
+    );
+}
+
+#[test]
+fn test_v2_endpoint() {
+    let (host, recv) = serve();
+    let base_url = Url::parse(&format!("http://{}/marketstack", host)).unwrap();
+
+    let client = Marketstack::builder("ignored.host.invalid", "token")
+        .lazy()
+        .base_url(base_url)
+        .build()
+        .unwrap();
+    let endpoint = TickersList::builder().search("AAPL").build().unwrap();
+    let tickers = endpoint.fetch(&client).unwrap();
+
+    assert!(tickers.is_empty());
+    assert_eq!(
+        recv.recv().unwrap().request_line,
+        "GET /marketstack/v2/tickerslist?search=AAPL&access_key=token HTTP/1.1",
+    );
+}
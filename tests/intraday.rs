@@ -3,15 +3,13 @@ use chrono::NaiveDate;
 use marketstack::api::common::Interval;
 use marketstack::api::intraday::Intraday;
 use marketstack::api::Query;
-use marketstack::{IntradayData, Marketstack};
+use marketstack::IntradayData;
 
 mod setup;
 
 #[test]
-#[ignore]
 fn test_intraday() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("intraday/intraday");
 
     let endpoint = Intraday::builder().symbol("AAPL").build().unwrap();
     let intraday_result: IntradayData = endpoint.query(&client).unwrap();
@@ -24,10 +22,8 @@ fn test_intraday() {
 }
 
 #[test]
-#[ignore]
 fn test_intraday_paged() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("intraday/intraday_paged");
 
     let endpoint = Intraday::builder()
         .symbol("AAPL")
@@ -43,10 +39,8 @@ fn test_intraday_paged() {
 }
 
 #[test]
-#[ignore]
 fn test_intraday_latest() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("intraday/intraday_latest");
 
     let endpoint = Intraday::builder()
         .symbol("AAPL")
@@ -63,10 +57,8 @@ fn test_intraday_latest() {
 }
 
 #[test]
-#[ignore]
 fn test_intraday_date() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("intraday/intraday_date");
 
     let endpoint = Intraday::builder()
         .symbol("AAPL")
@@ -85,10 +77,8 @@ fn test_intraday_date() {
 }
 
 #[test]
-#[ignore]
 fn test_intraday_date_interval() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("intraday/intraday_date_interval");
 
     let endpoint = Intraday::builder()
         .symbol("AAPL")
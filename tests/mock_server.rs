@@ -0,0 +1,222 @@
+use std::time::Duration;
+
+use marketstack::api::eod::Eod;
+use marketstack::api::exchanges::Exchanges;
+use marketstack::api::paged::Pagination;
+use marketstack::api::tickers::Tickers;
+use marketstack::api::timezones::Timezones;
+use marketstack::api::{self, ApiError, AsyncQuery, ErrorCode, Query, TypedEndpoint};
+use marketstack::{
+    AsyncMarketstack, ConnectionError, EodData, EodDataItem, ExchangesResponse, Marketstack,
+    MarketstackError, RetryPolicy, TickersResponse, TimezonesDataItem,
+};
+use marketstack_mock::{Failure, MockServer};
+
+fn client(server: &MockServer) -> Marketstack {
+    Marketstack::builder(server.host(), server.access_key())
+        .insecure()
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_eod() {
+    let server = MockServer::start().unwrap();
+    let client = client(&server);
+
+    let endpoint = Eod::builder()
+        .symbol("AAPL")
+        .limit(5)
+        .unwrap()
+        .build()
+        .unwrap();
+    let eod: EodData = endpoint.query(&client).unwrap();
+
+    assert_eq!(eod.data.len(), 5);
+    assert_eq!(eod.pagination.total, 100);
+    assert!(eod.has_more());
+    assert_eq!(
+        server.requests().last().unwrap(),
+        "/v1/eod?symbols=AAPL&limit=5",
+    );
+}
+
+#[test]
+fn test_paged() {
+    let server = MockServer::start().unwrap();
+    let client = client(&server);
+
+    let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+    let rows: Vec<EodDataItem> = api::paged(endpoint, Pagination::All)
+        .query(&client)
+        .unwrap();
+
+    assert_eq!(rows.len(), 100);
+    assert!(rows.windows(2).all(|pair| pair[0].date > pair[1].date));
+    assert_eq!(
+        server.requests()[1..],
+        ["/v1/eod?symbols=AAPL&limit=1000&offset=0"],
+    );
+}
+
+#[test]
+fn test_paged_across_pages() {
+    let server = MockServer::builder().max_page_size(30).start().unwrap();
+    let client = client(&server);
+
+    let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+    let rows: Vec<EodDataItem> = api::paged(endpoint.clone(), Pagination::All)
+        .query(&client)
+        .unwrap();
+
+    assert_eq!(rows.len(), 100);
+    assert!(rows.windows(2).all(|pair| pair[0].date > pair[1].date));
+    assert_eq!(
+        server.requests()[1..],
+        [
+            "/v1/eod?symbols=AAPL&limit=1000&offset=0",
+            "/v1/eod?symbols=AAPL&limit=1000&offset=30",
+            "/v1/eod?symbols=AAPL&limit=1000&offset=60",
+            "/v1/eod?symbols=AAPL&limit=1000&offset=90",
+        ],
+    );
+
+    let rows: Vec<EodDataItem> = api::paged(endpoint, Pagination::Limit(45))
+        .query(&client)
+        .unwrap();
+
+    assert_eq!(rows.len(), 45);
+    assert_eq!(
+        server.requests()[5..],
+        [
+            "/v1/eod?symbols=AAPL&limit=45&offset=0",
+            "/v1/eod?symbols=AAPL&limit=15&offset=30",
+        ],
+    );
+}
+
+#[test]
+fn test_paged_limit() {
+    let server = MockServer::start().unwrap();
+    let client = client(&server);
+
+    let endpoint = Timezones::builder().limit(1).unwrap().build().unwrap();
+    let timezones: Vec<TimezonesDataItem> = api::paged(endpoint, Pagination::Limit(2))
+        .query(&client)
+        .unwrap();
+
+    assert_eq!(timezones.len(), 2);
+}
+
+#[test]
+fn test_single_resources() {
+    let server = MockServer::start().unwrap();
+    let client = client(&server);
+
+    let endpoint = Tickers::builder().ticker("AAPL").build().unwrap();
+    match endpoint.fetch(&client).unwrap() {
+        TickersResponse::Ticker(ticker) => assert_eq!(ticker.name, "Apple Inc"),
+        other => panic!("unexpected response: {:?}", other),
+    }
+
+    let endpoint = Exchanges::builder().mic("XNAS").build().unwrap();
+    match endpoint.fetch(&client).unwrap() {
+        ExchangesResponse::Exchange(exchange) => {
+            assert_eq!(exchange.stock_exchange.acronym, "NASDAQ")
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[test]
+fn test_not_found() {
+    let server = MockServer::start().unwrap();
+    let client = client(&server);
+
+    let endpoint = Tickers::builder().ticker("NOPE").build().unwrap();
+    let err = endpoint.fetch(&client).unwrap_err();
+
+    assert_eq!(err.error_code(), Some(&ErrorCode::NotFound));
+}
+
+#[test]
+fn test_invalid_access_key() {
+    let server = MockServer::start().unwrap();
+
+    let err = Marketstack::builder(server.host(), "wrong")
+        .insecure()
+        .build()
+        .unwrap_err();
+
+    assert!(
+        matches!(
+            err,
+            MarketstackError::Connection {
+                source: ConnectionError::InvalidKey { .. },
+            },
+        ),
+        "unexpected error: {}",
+        err,
+    );
+}
+
+#[test]
+fn test_injected_failure() {
+    let server = MockServer::start().unwrap();
+    let client = client(&server);
+    server.fail_next(Failure::RateLimited);
+
+    let endpoint = Timezones::builder().build().unwrap();
+    let err = endpoint.fetch(&client).unwrap_err();
+
+    if let ApiError::MarketstackError { code, status, .. } = err {
+        assert_eq!(code, ErrorCode::RateLimitReached);
+        assert_eq!(status, 429);
+    } else {
+        panic!("unexpected error: {}", err);
+    }
+    endpoint.fetch(&client).unwrap();
+}
+
+#[test]
+fn test_retry_injected_failures() {
+    let server = MockServer::start().unwrap();
+    let policy = RetryPolicy::builder()
+        .initial_delay(Duration::from_millis(1))
+        .build()
+        .unwrap();
+    let client = Marketstack::builder(server.host(), server.access_key())
+        .insecure()
+        .lazy()
+        .retry(policy)
+        .build()
+        .unwrap();
+    server.fail_next(Failure::RateLimited);
+    server.fail_next(Failure::InternalError);
+
+    let endpoint = Timezones::builder().build().unwrap();
+    let timezones = endpoint.fetch(&client).unwrap();
+
+    assert_eq!(timezones.len(), 3);
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn test_async() {
+    let server = MockServer::start().unwrap();
+    let client: AsyncMarketstack = Marketstack::builder(server.host(), server.access_key())
+        .insecure()
+        .build_async()
+        .await
+        .unwrap();
+
+    let endpoint = Eod::builder().symbol("AAPL").latest(true).build().unwrap();
+    let eod: EodData = endpoint.query_async(&client).await.unwrap();
+
+    assert_eq!(eod.data.len(), 1);
+    assert_eq!(eod.data[0].symbol, "AAPL");
+
+    let endpoint = Tickers::builder().ticker("NOPE").build().unwrap();
+    let err = endpoint.fetch_async(&client).await.unwrap_err();
+    assert_eq!(err.error_code(), Some(&ErrorCode::NotFound));
+}
@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use marketstack::api::currencies::Currencies;
+use marketstack::api::{ApiError, AsyncQuery, Query};
+use marketstack::{CurrenciesData, Jitter, Marketstack, RetryPolicy};
+
+const EMPTY: &str = r#"{"pagination":{"limit":100,"offset":0,"count":0,"total":0},"data":[]}"#;
+const RATE_LIMITED: &str = r#"{"error":{"code":"rate_limit_reached","message":"slow down"}}"#;
+
+/// Answer requests with the given statuses in order, recording each request line.
+///
+/// Successful statuses are answered with an empty page, others with a rate limit error.
+fn serve<I>(statuses: I) -> (String, Arc<Mutex<Vec<String>>>)
+where
+    I: IntoIterator<Item = u16>,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let mut statuses: VecDeque<_> = statuses.into_iter().collect();
+    let requests = Arc::new(Mutex::new(Vec::new()));
+
+    let received = Arc::clone(&requests);
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut reader = BufReader::new(stream.unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+                line.clear();
+            }
+            received
+                .lock()
+                .unwrap()
+                .push(request_line.trim_end().to_owned());
+
+            let status = statuses.pop_front().expect("unexpected request");
+            let body = if status == 200 { EMPTY } else { RATE_LIMITED };
+            let mut stream = reader.into_inner();
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {} Scripted\r\ncontent-type: application/json\r\ncontent-length: {}\r\nretry-after: 0\r\nconnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body,
+            );
+        }
+    });
+
+    (format!("127.0.0.1:{}", port), requests)
+}
+
+fn policy(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy::builder()
+        .max_attempts(max_attempts)
+        .initial_delay(Duration::from_millis(1))
+        .jitter(Jitter::None)
+        .build()
+        .unwrap()
+}
+
+fn client(host: String, policy: RetryPolicy) -> Marketstack {
+    Marketstack::builder(host, "token")
+        .insecure()
+        .lazy()
+        .retry(policy)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_retry_until_success() {
+    let (host, requests) = serve([429, 503, 200]);
+    let client = client(host, policy(3));
+
+    let _: CurrenciesData = Currencies::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 3);
+    assert!(requests
+        .iter()
+        .all(|line| line == "GET /v1/currencies?access_key=token HTTP/1.1"));
+}
+
+#[test]
+fn test_retry_gives_up() {
+    let (host, requests) = serve([429, 429]);
+    let client = client(host, policy(2));
+
+    let err = Currencies::builder()
+        .build()
+        .unwrap()
+        .query(&client)
+        .map(|_: CurrenciesData| ())
+        .unwrap_err();
+
+    if let ApiError::MarketstackError { status, .. } = err {
+        assert_eq!(status, 429);
+    } else {
+        panic!("unexpected error: {}", err);
+    }
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_async_retry_until_success() {
+    let (host, requests) = serve([500, 200]);
+    let client = Marketstack::builder(host, "token")
+        .insecure()
+        .lazy()
+        .retry(policy(3))
+        .build_async()
+        .await
+        .unwrap();
+
+    let _: CurrenciesData = Currencies::builder()
+        .build()
+        .unwrap()
+        .query_async(&client)
+        .await
+        .unwrap();
+
+    assert_eq!(requests.lock().unwrap().len(), 2);
+}
@@ -1,8 +1,46 @@
+#![allow(dead_code)]
+
 use dotenvy::dotenv;
+use marketstack::{AsyncMarketstack, Cassette, Marketstack};
 use std::env;
+use std::path::PathBuf;
 
 pub fn setup_key() -> String {
     dotenv().expect(".env file does not exist");
 
     env::var("MARKETSTACK_API_KEY").expect("MARKETSTACK_API_KEY must be set.")
 }
+
+/// Whether cassettes should be recorded against the live API rather than replayed.
+///
+/// Set `MARKETSTACK_RECORD=1` (along with `MARKETSTACK_API_KEY`) to refresh the fixtures.
+fn recording() -> bool {
+    env::var_os("MARKETSTACK_RECORD").is_some_and(|value| value != "0")
+}
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join(format!("{}.json", name))
+}
+
+pub fn cassette(name: &str) -> Cassette<Marketstack> {
+    if recording() {
+        let client = Marketstack::new_insecure("api.marketstack.com", setup_key()).unwrap();
+        Cassette::record(client, fixture(name))
+    } else {
+        Cassette::replay(fixture(name)).unwrap()
+    }
+}
+
+pub async fn cassette_async(name: &str) -> Cassette<AsyncMarketstack> {
+    if recording() {
+        let client = AsyncMarketstack::new_insecure("api.marketstack.com", setup_key())
+            .await
+            .unwrap();
+        Cassette::record(client, fixture(name))
+    } else {
+        Cassette::replay(fixture(name)).unwrap()
+    }
+}
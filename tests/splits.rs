@@ -1,15 +1,13 @@
 use chrono::NaiveDate;
 
 use marketstack::api::{splits, AsyncQuery, Query};
-use marketstack::{AsyncMarketstack, Marketstack, SplitsData};
+use marketstack::SplitsData;
 
 mod setup;
 
 #[test]
-#[ignore]
 fn test_splits() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("splits/splits");
 
     let endpoint = splits::Splits::builder()
         .symbol("AAPL")
@@ -26,12 +24,8 @@ fn test_splits() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_async_splits() {
-    let api_key = setup::setup_key();
-    let client = AsyncMarketstack::new_insecure("api.marketstack.com", api_key)
-        .await
-        .unwrap();
+    let client = setup::cassette_async("splits/async_splits").await;
 
     let endpoint = splits::Splits::builder()
         .limit(3)
@@ -48,10 +42,8 @@ async fn test_async_splits() {
 }
 
 #[test]
-#[ignore]
 fn test_splits_date() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("splits/splits_date");
 
     let endpoint = splits::Splits::builder()
         .symbol("AAPL")
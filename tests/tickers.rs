@@ -1,19 +1,18 @@
 use chrono::NaiveDate;
 use marketstack::api::common::SortOrder;
 use marketstack::api::dividends::Dividends;
+use marketstack::api::intraday::Intraday;
 use marketstack::api::splits::Splits;
-use marketstack::api::{eod, tickers, Query};
+use marketstack::api::{eod, tickers, Query, TypedEndpoint};
 use marketstack::{
-    DividendsData, EodDataItem, Marketstack, SplitsData, TickersData, TickersEodData,
+    DividendsData, EodDataItem, SplitsData, TickersData, TickersEodData, TickersResponse,
 };
 
 mod setup;
 
 #[test]
-#[ignore]
 fn test_tickers() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("tickers/tickers");
 
     let endpoint = tickers::Tickers::builder()
         .limit(3)
@@ -26,10 +25,8 @@ fn test_tickers() {
 }
 
 #[test]
-#[ignore]
 fn test_tickers_eod() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("tickers/tickers_eod");
 
     let endpoint = tickers::Tickers::builder()
         .ticker("AAPL")
@@ -48,10 +45,8 @@ fn test_tickers_eod() {
 }
 
 #[test]
-#[ignore]
 fn test_tickers_eod_latest() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("tickers/tickers_eod_latest");
 
     let endpoint = tickers::Tickers::builder()
         .ticker("AAPL")
@@ -65,10 +60,8 @@ fn test_tickers_eod_latest() {
 }
 
 #[test]
-#[ignore]
 fn test_tickers_eod_date() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("tickers/tickers_eod_date");
 
     let endpoint = tickers::Tickers::builder()
         .ticker("AAPL")
@@ -87,10 +80,8 @@ fn test_tickers_eod_date() {
 }
 
 #[test]
-#[ignore]
 fn test_tickers_splits() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("tickers/tickers_splits");
 
     let endpoint = tickers::Tickers::builder()
         .ticker("AAPL")
@@ -104,10 +95,8 @@ fn test_tickers_splits() {
 }
 
 #[test]
-#[ignore]
 fn test_tickers_dividends() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("tickers/tickers_dividends");
 
     let endpoint = tickers::Tickers::builder()
         .ticker("AAPL")
@@ -119,3 +108,70 @@ fn test_tickers_dividends() {
 
     assert_eq!(tickers_dividends_result.data[0].symbol, "AAPL")
 }
+
+#[test]
+fn test_tickers_fetch() {
+    let client = setup::cassette("tickers/tickers");
+
+    let endpoint = tickers::Tickers::builder()
+        .limit(3)
+        .unwrap()
+        .build()
+        .unwrap();
+
+    match endpoint.fetch(&client).unwrap() {
+        TickersResponse::List(tickers_result) => assert_eq!(tickers_result.pagination.limit, 3),
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[test]
+fn test_tickers_eod_latest_fetch() {
+    let client = setup::cassette("tickers/tickers_eod_latest");
+
+    let endpoint = tickers::Tickers::builder()
+        .ticker("AAPL")
+        .eod(eod::Eod::builder().latest(true).build().unwrap())
+        .build()
+        .unwrap();
+
+    match endpoint.fetch(&client).unwrap() {
+        TickersResponse::EodDay(eod) => assert_eq!(eod.symbol, "AAPL"),
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[test]
+fn test_tickers_splits_fetch() {
+    let client = setup::cassette("tickers/tickers_splits");
+
+    let endpoint = tickers::Tickers::builder()
+        .ticker("AAPL")
+        .splits(Splits::builder().build().unwrap())
+        .build()
+        .unwrap();
+
+    match endpoint.fetch(&client).unwrap() {
+        TickersResponse::Splits(splits) => assert_eq!(splits.data[0].symbol, "AAPL"),
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[test]
+fn test_tickers_intraday() {
+    let client = setup::cassette("tickers/tickers_intraday");
+
+    let endpoint = tickers::Tickers::builder()
+        .ticker("AAPL")
+        .intraday(Intraday::builder().limit(2).unwrap().build().unwrap())
+        .build()
+        .unwrap();
+
+    match endpoint.fetch(&client).unwrap() {
+        TickersResponse::Intraday(intraday) => {
+            assert_eq!(intraday.data.symbol, "AAPL");
+            assert_eq!(intraday.data.intraday.len(), 2);
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
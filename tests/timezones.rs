@@ -1,13 +1,11 @@
 use marketstack::api::{timezones, AsyncQuery, Query};
-use marketstack::{AsyncMarketstack, Marketstack, TimezonesData};
+use marketstack::TimezonesData;
 
 mod setup;
 
 #[test]
-#[ignore]
 fn test_timezones() {
-    let api_key = setup::setup_key();
-    let client = Marketstack::new_insecure("api.marketstack.com", api_key).unwrap();
+    let client = setup::cassette("timezones/timezones");
 
     let endpoint = timezones::Timezones::builder()
         .limit(3)
@@ -23,12 +21,8 @@ fn test_timezones() {
 }
 
 #[tokio::test]
-#[ignore]
 async fn test_async_timezones() {
-    let api_key = setup::setup_key();
-    let client = AsyncMarketstack::new_insecure("api.marketstack.com", api_key)
-        .await
-        .unwrap();
+    let client = setup::cassette_async("timezones/async_timezones").await;
 
     let endpoint = timezones::Timezones::builder()
         .limit(3)
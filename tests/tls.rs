@@ -0,0 +1,160 @@
+use std::error::Error;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread;
+
+use marketstack::api::eod::Eod;
+use marketstack::api::{AsyncQuery, Query};
+use marketstack::{EodData, Marketstack};
+use native_tls::{Identity, TlsAcceptor};
+
+const CERT: &[u8] = include_bytes!("fixtures/tls/localhost.crt");
+const KEY: &[u8] = include_bytes!("fixtures/tls/localhost.key");
+
+const EMPTY_EOD: &str = r#"{"pagination":{"limit":100,"offset":0,"count":0,"total":0},"data":[]}"#;
+
+/// Serve an empty `eod` response over HTTPS with a self-signed certificate.
+///
+/// Returns the `host:port` the server listens on.
+fn serve_self_signed() -> String {
+    let identity = Identity::from_pkcs8(CERT, KEY).unwrap();
+    let acceptor = TlsAcceptor::new(identity).unwrap();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let stream = match stream.map(|stream| acceptor.accept(stream)) {
+                Ok(Ok(stream)) => stream,
+                // Clients rejecting the certificate abort the handshake.
+                _ => continue,
+            };
+
+            let mut reader = BufReader::new(stream);
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+                line.clear();
+            }
+
+            let mut stream = reader.into_inner();
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                EMPTY_EOD.len(),
+                EMPTY_EOD,
+            );
+            let _ = stream.shutdown();
+        }
+    });
+
+    format!("localhost:{}", port)
+}
+
+/// Whether the request failed while connecting, which includes the TLS handshake.
+fn is_connect_error(err: &(dyn Error + 'static)) -> bool {
+    let mut source = Some(err);
+    while let Some(err) = source {
+        if err
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(reqwest::Error::is_connect)
+        {
+            return true;
+        }
+        source = err.source();
+    }
+    false
+}
+
+#[test]
+fn test_rejects_self_signed_by_default() {
+    let host = serve_self_signed();
+
+    let err = Marketstack::builder(host, "token").build().unwrap_err();
+
+    assert!(is_connect_error(&err), "unexpected error: {:?}", err);
+}
+
+#[test]
+fn test_trusts_added_root_certificate() {
+    let host = serve_self_signed();
+
+    let client = Marketstack::builder(host, "token")
+        .add_root_certificate_pem(CERT)
+        .build()
+        .unwrap();
+
+    let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+    let eod: EodData = endpoint.query(&client).unwrap();
+    assert!(eod.data.is_empty());
+}
+
+#[test]
+fn test_accepts_invalid_certs_when_opted_in() {
+    let host = serve_self_signed();
+
+    Marketstack::builder(host, "token")
+        .danger_accept_invalid_certs()
+        .build()
+        .unwrap();
+}
+
+#[test]
+fn test_client_identity() {
+    let host = serve_self_signed();
+
+    Marketstack::builder(host, "token")
+        .add_root_certificate_pem(CERT)
+        .client_identity_from_pkcs8_pem(CERT, KEY)
+        .build()
+        .unwrap();
+}
+
+#[test]
+fn test_invalid_client_identity() {
+    let host = serve_self_signed();
+
+    let err = Marketstack::builder(host, "token")
+        .add_root_certificate_pem(CERT)
+        .client_identity_from_pkcs8_pem(CERT, b"not a key")
+        .build()
+        .unwrap_err();
+
+    assert!(err.to_string().contains("builder error"), "{}", err);
+}
+
+#[test]
+fn test_invalid_root_certificate() {
+    let err = Marketstack::builder("localhost", "token")
+        .add_root_certificate_der(b"not a certificate")
+        .build()
+        .unwrap_err();
+
+    assert!(err.to_string().contains("builder error"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_async_rejects_self_signed_by_default() {
+    let host = serve_self_signed();
+
+    let err = Marketstack::builder(host, "token")
+        .build_async()
+        .await
+        .unwrap_err();
+
+    assert!(is_connect_error(&err), "unexpected error: {:?}", err);
+}
+
+#[tokio::test]
+async fn test_async_trusts_added_root_certificate() {
+    let host = serve_self_signed();
+
+    let client = Marketstack::builder(host, "token")
+        .add_root_certificate_pem(CERT)
+        .build_async()
+        .await
+        .unwrap();
+
+    let endpoint = Eod::builder().symbol("AAPL").build().unwrap();
+    let eod: EodData = endpoint.query_async(&client).await.unwrap();
+    assert!(eod.data.is_empty());
+}